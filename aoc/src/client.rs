@@ -0,0 +1,248 @@
+//! Talking to adventofcode.com
+//!
+//! Requests are made as the logged-in user whose `session` cookie is in
+//! `$AOC_SESSION`, or in `.aoc-session` at the workspace root (ignored by
+//! git). `$AOC_URL` points the client at another server.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use ureq::Agent;
+
+/// Environment variable holding the session cookie
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// File at the workspace root holding the session cookie
+pub const SESSION_FILE: &str = ".aoc-session";
+
+/// Environment variable overriding the server
+pub const URL_ENV: &str = "AOC_URL";
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2025;
+
+/// Sent with every request, as the site asks automated tools to identify
+/// themselves and say how to get in touch
+pub const USER_AGENT: &str = concat!(
+    "aoc-2025-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/denysvitali/aoc-2025)"
+);
+
+/// Why a request did not get what it asked for
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie is configured
+    NoSession,
+    /// The session cookie was refused, usually because it expired
+    BadSession,
+    /// The puzzle has not been released yet
+    Locked { day: u8 },
+    /// Any other unexpected response
+    Status { status: u16, body: String },
+    /// The server could not be reached
+    Http(ureq::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session cookie: set ${} or put it in {}",
+                SESSION_ENV, SESSION_FILE
+            ),
+            ClientError::BadSession => write!(
+                f,
+                "the session cookie was refused; log in again and update ${} or {}",
+                SESSION_ENV, SESSION_FILE
+            ),
+            ClientError::Locked { day } => write!(f, "day {} is not unlocked yet", day),
+            ClientError::Status { status, body } => {
+                let first_line = body.lines().next().unwrap_or("");
+                write!(f, "unexpected response {}: {}", status, first_line)
+            }
+            ClientError::Http(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+/// The session cookie from `$AOC_SESSION` or `<root>/.aoc-session`
+pub fn session_token(root: &Path) -> Result<String, ClientError> {
+    let token = match std::env::var(SESSION_ENV) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(root.join(SESSION_FILE)).unwrap_or_default(),
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(ClientError::NoSession);
+    }
+    Ok(token.to_string())
+}
+
+pub struct Client {
+    url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(url: &str, session: &str) -> Client {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client {
+            url: url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    /// A client for `$AOC_URL` (or the real site) using the configured session
+    pub fn from_env(root: &Path) -> Result<Client, ClientError> {
+        let url = std::env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_string());
+        Ok(Client::new(&url, &session_token(root)?))
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.url, YEAR, day)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Status and body of a GET request
+    fn get(&self, url: &str) -> Result<(u16, String), ClientError> {
+        let mut response = self.agent.get(url).header("Cookie", self.cookie()).call()?;
+        let status = response.status().as_u16();
+        Ok((status, response.body_mut().read_to_string()?))
+    }
+
+    /// Download the puzzle input for a day
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let (status, body) = self.get(&format!("{}/input", self.day_url(day)))?;
+        check_status(day, status, body)
+    }
+
+    /// Submit an answer, returning the page the site responds with
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String, ClientError> {
+        let mut response = self
+            .agent
+            .post(format!("{}/answer", self.day_url(day)))
+            .header("Cookie", self.cookie())
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        check_status(day, status, body)
+    }
+}
+
+/// The body of a successful response, or what went wrong
+fn check_status(day: u8, status: u16, body: String) -> Result<String, ClientError> {
+    match status {
+        200 => Ok(body),
+        404 => Err(ClientError::Locked { day }),
+        // Logged-out requests get a 400, some bad cookies a 500
+        400 => Err(ClientError::BadSession),
+        500 if body.contains("log in") => Err(ClientError::BadSession),
+        _ => Err(ClientError::Status { status, body }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const LOCKED: &str = "Please don't repeatedly request this endpoint before it unlocks! \
+        The calendar countdown is synchronized with the server time; the link will be \
+        enabled on the calendar the instant this puzzle becomes available.\n";
+    const LOGGED_OUT: &str =
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+    #[test]
+    fn test_input_sends_session_and_user_agent() {
+        let server = MockServer::start(|_| (200, "1 2 3\n".to_string()));
+        let client = Client::new(&server.url, "abc123");
+        assert_eq!(client.input(5).unwrap(), "1 2 3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/5/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+        assert!(USER_AGENT.contains("github.com/denysvitali/aoc-2025"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2025/day/12/input" => (404, LOCKED.to_string()),
+            "/2025/day/2/input" => (400, LOGGED_OUT.to_string()),
+            "/2025/day/3/input" => (500, "Internal Server Error\n".to_string()),
+            _ => (400, LOGGED_OUT.to_string()),
+        });
+        let client = Client::new(&server.url, "expired");
+        assert!(matches!(
+            client.input(12),
+            Err(ClientError::Locked { day: 12 })
+        ));
+        assert!(matches!(client.input(2), Err(ClientError::BadSession)));
+        assert!(matches!(
+            client.input(3),
+            Err(ClientError::Status { status: 500, .. })
+        ));
+    }
+
+    #[test]
+    fn test_answer_posts_form() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                "<article><p>That's the right answer!</p></article>".to_string(),
+            )
+        });
+        let client = Client::new(&server.url, "abc123");
+        let page = client.answer(7, 2, "1234").unwrap();
+        assert!(page.contains("right answer"));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn test_unreachable_server() {
+        // A port that was free a moment ago
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let client = Client::new(&url, "abc123");
+        assert!(matches!(client.input(1), Err(ClientError::Http(_))));
+    }
+}
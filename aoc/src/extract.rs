@@ -0,0 +1,289 @@
+//! Pulling examples and their answers out of a saved puzzle page
+//!
+//! Each part of a puzzle is an `<article>`. The first `<pre><code>` block in
+//! part 1 is taken as the example, and the last emphasized `<code><em>` in
+//! each part as the answer the puzzle text gives for it. If part 2 shows an
+//! example of its own, that becomes `example2` with the part 2 answer;
+//! otherwise the part 2 answer goes with the first example.
+//!
+//! Puzzle pages are prose, so this is a starting point: the examples and
+//! `examples.toml` it writes are worth a look before relying on them.
+
+use common::{EXAMPLES_FILE, Example, Expected, parse_examples};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An example found on the page, with its input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageExample {
+    pub example: Example,
+    pub input: String,
+}
+
+/// Replace the entities puzzle pages use and drop any markup
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(tag) = rest.find('<') {
+        text.push_str(&rest[..tag]);
+        rest = rest[tag..]
+            .find('>')
+            .map_or("", |end| &rest[tag + end + 1..]);
+    }
+    text.push_str(rest);
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The contents of every `open ... close` section, in order
+fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let after = &rest[start + open.len()..];
+        let Some(end) = after.find(close) else {
+            break;
+        };
+        found.push(&after[..end]);
+        rest = &after[end + close.len()..];
+    }
+    found
+}
+
+/// The contents of each `<article>`, one per part
+fn articles(html: &str) -> Vec<&str> {
+    sections(html, "<article", "</article>")
+}
+
+fn code_blocks(article: &str) -> Vec<String> {
+    sections(article, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(text)
+        .collect()
+}
+
+/// The last emphasized code in a part, which is where the answer goes
+fn answer(article: &str) -> Option<String> {
+    let mut answers: Vec<(usize, &str)> = Vec::new();
+    for (open, close) in [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ] {
+        for section in sections(article, open, close) {
+            let at = section.as_ptr() as usize - article.as_ptr() as usize;
+            answers.push((at, section));
+        }
+    }
+    answers
+        .into_iter()
+        .max_by_key(|&(at, _)| at)
+        .map(|(_, answer)| text(answer).trim().to_string())
+}
+
+/// Find the examples and their answers on a saved puzzle page
+pub fn extract_examples(html: &str) -> Result<Vec<PageExample>, String> {
+    let articles = articles(html);
+    let part1 = articles
+        .first()
+        .ok_or("no puzzle description found: expected an <article>")?;
+    let input = code_blocks(part1)
+        .into_iter()
+        .next()
+        .ok_or("part 1 has no <pre><code> example")?;
+    let mut examples = vec![PageExample {
+        example: Example {
+            name: "example".to_string(),
+            expected: Expected {
+                part1: answer(part1),
+                part2: None,
+            },
+        },
+        input,
+    }];
+
+    if let Some(part2) = articles.get(1) {
+        let expected = answer(part2);
+        let new_input = code_blocks(part2)
+            .into_iter()
+            .next()
+            .filter(|input| *input != examples[0].input);
+        match new_input {
+            Some(input) => examples.push(PageExample {
+                example: Example {
+                    name: "example2".to_string(),
+                    expected: Expected {
+                        part1: None,
+                        part2: expected,
+                    },
+                },
+                input,
+            }),
+            None => examples[0].example.expected.part2 = expected,
+        }
+    }
+    Ok(examples)
+}
+
+/// An `examples.toml` listing the examples, with unknown answers commented out
+pub fn manifest(examples: &[PageExample]) -> String {
+    let mut manifest =
+        "# Answers the puzzle text gives for each example file in this directory\n".to_string();
+    for PageExample { example, .. } in examples {
+        manifest.push_str(&format!("\n[{}]\n", example.name));
+        for part in [1, 2] {
+            match example.expected.part(part) {
+                Some(answer) if answer.parse::<i128>().is_ok() => {
+                    manifest.push_str(&format!("part{} = {}\n", part, answer));
+                }
+                Some(answer) => manifest.push_str(&format!("part{} = \"{}\"\n", part, answer)),
+                None => manifest.push_str(&format!("# part{} =\n", part)),
+            }
+        }
+    }
+    manifest
+}
+
+/// Whether a file holds something worth keeping: a non-blank example, or a
+/// manifest with at least one answer filled in
+fn has_content(path: &Path) -> bool {
+    let Ok(text) = fs::read_to_string(path) else {
+        return false;
+    };
+    if path.ends_with(EXAMPLES_FILE) {
+        // A manifest that does not parse was edited by hand too
+        parse_examples(&text).map_or(true, |examples| {
+            examples
+                .iter()
+                .any(|e| e.expected.part1.is_some() || e.expected.part2.is_some())
+        })
+    } else {
+        !text.trim().is_empty()
+    }
+}
+
+/// Write the examples and their manifest into a day's `input/` directory
+///
+/// Example files and a manifest that already have content are only replaced
+/// with `force`.
+pub fn write_examples(
+    dir: &Path,
+    examples: &[PageExample],
+    force: bool,
+) -> Result<Vec<PathBuf>, String> {
+    let mut files: Vec<(PathBuf, String)> = examples
+        .iter()
+        .map(|e| (dir.join(format!("{}.txt", e.example.name)), e.input.clone()))
+        .collect();
+    files.push((dir.join(EXAMPLES_FILE), manifest(examples)));
+    if !force && let Some((path, _)) = files.iter().find(|(path, _)| has_content(path)) {
+        return Err(format!(
+            "{} already has content; pass --force to replace it",
+            path.display()
+        ));
+    }
+
+    for (path, contents) in &files {
+        fs::write(path, contents)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse_examples;
+
+    /// Laid out like a saved day 11 page after solving part 1
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><body>
+<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1></div></header>
+<main>
+<article class="day-desc"><h2>--- Day 11: Reactor ---</h2><p>For example:</p>
+<pre><code>you: bbb ccc
+bbb: ddd eee
+ccc: out
+</code></pre>
+<p>Every path from <code>you</code> leads to <code>out</code>; there are <code><em>5</em></code> different paths.</p>
+</article>
+<p>Your puzzle answer was <code>607</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>For example:</p>
+<pre><code>svr: aaa bbb
+aaa: <em>fft</em> &amp; out
+</code></pre>
+<p>However, only <em><code>2</code></em> paths visit both <code>dac</code> and <code>fft</code>.</p>
+</article>
+</main></body></html>
+"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PAGE).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].example.name, "example");
+        assert_eq!(examples[0].input, "you: bbb ccc\nbbb: ddd eee\nccc: out\n");
+        assert_eq!(examples[0].example.expected.part(1), Some("5"));
+        assert_eq!(examples[0].example.expected.part(2), None);
+        assert_eq!(examples[1].example.name, "example2");
+        assert_eq!(examples[1].input, "svr: aaa bbb\naaa: fft & out\n");
+        assert_eq!(examples[1].example.expected.part(2), Some("2"));
+    }
+
+    #[test]
+    fn test_part2_reusing_the_example() {
+        let page = "<article><pre><code>1\n2\n</code></pre><p><code><em>3</em></code></p></article>\
+            <article><pre><code>1\n2\n</code></pre><p><code><em>abc</em></code></p></article>";
+        let examples = extract_examples(page).unwrap();
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].example.expected.part(2), Some("abc"));
+
+        assert!(extract_examples("<html></html>").is_err());
+        assert!(extract_examples("<article><p>No example</p></article>").is_err());
+    }
+
+    #[test]
+    fn test_manifest_parses_back() {
+        let examples = extract_examples(PAGE).unwrap();
+        let manifest = manifest(&examples);
+        assert!(manifest.contains("[example]\npart1 = 5\n# part2 =\n"));
+        let parsed = parse_examples(&manifest).unwrap();
+        let expected: Vec<Example> = examples.into_iter().map(|e| e.example).collect();
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_write_examples_keeps_existing() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        // The placeholders from `aoc new` are fine to replace
+        fs::write(dir.join("example.txt"), "").unwrap();
+        let template = include_str!("../../template/examples.toml.template");
+        fs::write(dir.join(EXAMPLES_FILE), template).unwrap();
+        let examples = extract_examples(PAGE).unwrap();
+
+        let files = write_examples(&dir, &examples, false).unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(
+            fs::read_to_string(dir.join("example2.txt")).unwrap(),
+            examples[1].input
+        );
+        assert!(write_examples(&dir, &examples, false).is_err());
+        assert!(write_examples(&dir, &examples, true).is_ok());
+
+        // Answers filled in by hand are kept too
+        for file in ["example.txt", "example2.txt"] {
+            fs::remove_file(dir.join(file)).unwrap();
+        }
+        fs::write(dir.join(EXAMPLES_FILE), "[example]\npart1 = 6\n").unwrap();
+        assert!(write_examples(&dir, &examples, false).is_err());
+        assert_eq!(
+            fs::read_to_string(dir.join(EXAMPLES_FILE)).unwrap(),
+            "[example]\npart1 = 6\n"
+        );
+    }
+}
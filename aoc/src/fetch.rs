@@ -0,0 +1,117 @@
+//! Downloading puzzle inputs into `dayNN/input/input.txt`
+//!
+//! An input never changes once released, so a day whose input is already
+//! there, in plain text or sealed, is not downloaded again.
+
+use crate::client::Client;
+use common::sealed::sealed_path;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What `fetch_input` did
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there
+    Cached(PathBuf),
+    /// The input was downloaded and saved
+    Downloaded(PathBuf),
+}
+
+/// Where a day's input is cached in the workspace at `root`
+pub fn input_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day))
+        .join("input")
+        .join("input.txt")
+}
+
+/// Download a day's input unless it is already cached
+///
+/// A sealed copy counts as cached; the empty placeholder `aoc new` leaves
+/// behind does not.
+pub fn fetch_input(client: &Client, root: &Path, day: u8) -> Result<Fetched, String> {
+    let path = input_path(root, day);
+    if fs::read_to_string(&path).is_ok_and(|input| !input.trim().is_empty()) {
+        return Ok(Fetched::Cached(path));
+    }
+    let sealed = sealed_path(&path);
+    if sealed.exists() {
+        return Ok(Fetched::Cached(sealed));
+    }
+    let dir = path.parent().expect("input path has a directory");
+    if !dir.exists() {
+        return Err(format!(
+            "{} does not exist; create the day with `aoc new {}`",
+            dir.display(),
+            day
+        ));
+    }
+
+    let input = client
+        .input(day)
+        .map_err(|e| format!("Day {:02}: {}", day, e))?;
+    fs::write(&path, input).map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("day01/input")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let root = workspace("cache");
+        fs::write(input_path(&root, 1), "").unwrap();
+        let server = MockServer::start(|_| (200, "42\n".to_string()));
+        let client = Client::new(&server.url, "abc123");
+
+        let path = input_path(&root, 1);
+        assert_eq!(
+            fetch_input(&client, &root, 1),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "42\n");
+        assert_eq!(fetch_input(&client, &root, 1), Ok(Fetched::Cached(path)));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_keeps_sealed_input() {
+        let root = workspace("sealed");
+        let sealed = sealed_path(&input_path(&root, 1));
+        fs::write(&sealed, "sealed bytes").unwrap();
+        let server = MockServer::start(|_| (200, "42\n".to_string()));
+        let client = Client::new(&server.url, "abc123");
+
+        assert_eq!(fetch_input(&client, &root, 1), Ok(Fetched::Cached(sealed)));
+        assert!(!input_path(&root, 1).exists());
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_fetch_failures_leave_no_file() {
+        let root = workspace("failures");
+        let server = MockServer::start(|_| {
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!\n".to_string(),
+            )
+        });
+        let client = Client::new(&server.url, "abc123");
+
+        let err = fetch_input(&client, &root, 1).unwrap_err();
+        assert_eq!(err, "Day 01: day 1 is not unlocked yet");
+        assert!(!input_path(&root, 1).exists());
+
+        let err = fetch_input(&client, &root, 2).unwrap_err();
+        assert!(err.contains("aoc new 2"));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,282 @@
+//! Benchmark history: one CSV row per day, phase and commit
+//!
+//! `aoc bench` appends to the file and `aoc compare` reads it back to find
+//! parts whose median got slower between two revisions.
+
+use common::bench::{BenchReport, Stats};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding where the history is kept
+pub const HISTORY_ENV: &str = "AOC_BENCH_HISTORY";
+
+const HEADER: &str = "day,part,revision,timestamp,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns";
+
+/// Revision recorded when git is not available
+pub const UNKNOWN_REVISION: &str = "unknown";
+
+/// One benchmarked phase of a day at some revision
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    /// `parse`, `1` or `2`
+    pub part: String,
+    pub revision: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub p95_ns: u128,
+    pub stddev_ns: u128,
+}
+
+impl Record {
+    fn new(day: u8, part: &str, revision: &str, timestamp: u64, stats: &Stats) -> Record {
+        Record {
+            day,
+            part: part.to_string(),
+            revision: revision.to_string(),
+            timestamp,
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            p95_ns: stats.p95.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+
+    /// One record per phase of a benchmark report
+    pub fn from_report(
+        day: u8,
+        revision: &str,
+        timestamp: u64,
+        report: &BenchReport,
+    ) -> Vec<Record> {
+        vec![
+            Record::new(day, "parse", revision, timestamp, &report.parse),
+            Record::new(day, "1", revision, timestamp, &report.part1),
+            Record::new(day, "2", revision, timestamp, &report.part2),
+        ]
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.revision,
+            self.timestamp,
+            self.runs,
+            self.min_ns,
+            self.median_ns,
+            self.mean_ns,
+            self.p95_ns,
+            self.stddev_ns
+        )
+    }
+
+    fn from_csv(line: &str) -> Result<Record, String> {
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 10 {
+            return Err(format!("expected 10 fields, found {}", fields.len()));
+        }
+        fn num<T: std::str::FromStr>(field: &str) -> Result<T, String> {
+            field
+                .parse()
+                .map_err(|_| format!("invalid number '{}'", field))
+        }
+        Ok(Record {
+            day: num(fields[0])?,
+            part: fields[1].to_string(),
+            revision: fields[2].to_string(),
+            timestamp: num(fields[3])?,
+            runs: num(fields[4])?,
+            min_ns: num(fields[5])?,
+            median_ns: num(fields[6])?,
+            mean_ns: num(fields[7])?,
+            p95_ns: num(fields[8])?,
+            stddev_ns: num(fields[9])?,
+        })
+    }
+}
+
+/// `$AOC_BENCH_HISTORY`, or `bench-history.csv` at the workspace root
+pub fn default_path() -> PathBuf {
+    match std::env::var_os(HISTORY_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => common::workspace_root().join("bench-history.csv"),
+    }
+}
+
+/// Append records, writing the header first if the file is new
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Read every record, oldest first
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read history {}: {}", path.display(), e))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| line != HEADER && !line.trim().is_empty())
+        .map(|(i, line)| {
+            Record::from_csv(line).map_err(|e| format!("{}:{}: {}", path.display(), i + 1, e))
+        })
+        .collect()
+}
+
+/// Distinct revisions in the order they were first recorded
+pub fn revisions(records: &[Record]) -> Vec<&str> {
+    let mut revisions: Vec<&str> = Vec::new();
+    for record in records {
+        if !revisions.contains(&record.revision.as_str()) {
+            revisions.push(&record.revision);
+        }
+    }
+    revisions
+}
+
+/// Median timings of one part at two revisions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: String,
+    pub base_ns: u128,
+    pub head_ns: u128,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent; positive is slower
+    pub fn change_percent(&self) -> f64 {
+        if self.base_ns == 0 {
+            return 0.0;
+        }
+        (self.head_ns as f64 - self.base_ns as f64) / self.base_ns as f64 * 100.0
+    }
+
+    pub fn regressed(&self, threshold_percent: f64) -> bool {
+        self.change_percent() > threshold_percent
+    }
+}
+
+/// Compare every part benchmarked at both revisions
+///
+/// When a part was benchmarked several times at one revision the latest
+/// record wins.
+pub fn compare(records: &[Record], base: &str, head: &str) -> Vec<Comparison> {
+    let latest = |revision: &str, day: u8, part: &str| {
+        records
+            .iter()
+            .rev()
+            .find(|r| r.revision == revision && r.day == day && r.part == part)
+    };
+
+    let mut comparisons: Vec<Comparison> = Vec::new();
+    for record in records.iter().filter(|r| r.revision == head) {
+        if comparisons
+            .iter()
+            .any(|c| c.day == record.day && c.part == record.part)
+        {
+            continue;
+        }
+        let (Some(base), Some(head)) = (
+            latest(base, record.day, &record.part),
+            latest(head, record.day, &record.part),
+        ) else {
+            continue;
+        };
+        comparisons.push(Comparison {
+            day: record.day,
+            part: record.part.clone(),
+            base_ns: base.median_ns,
+            head_ns: head.median_ns,
+        });
+    }
+    comparisons.sort_by(|a, b| (a.day, &a.part).cmp(&(b.day, &b.part)));
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: &str, revision: &str, median_ns: u128) -> Record {
+        Record {
+            day,
+            part: part.to_string(),
+            revision: revision.to_string(),
+            timestamp: 0,
+            runs: 10,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            p95_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.csv");
+        let _ = fs::remove_file(&path);
+
+        let first = vec![record(1, "1", "abc", 100), record(1, "2", "abc", 200)];
+        append(&path, &first).unwrap();
+        append(&path, &[record(1, "1", "def", 150)]).unwrap();
+
+        let records = load(&path).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[..2], first[..]);
+        assert_eq!(revisions(&records), vec!["abc", "def"]);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().matches(HEADER).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_bad_rows() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bad.csv");
+        fs::write(&path, format!("{}\n1,1,abc,0,10,x,1,1,1,0\n", HEADER)).unwrap();
+        assert!(load(&path).unwrap_err().contains(":2:"));
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let records = vec![
+            record(1, "1", "abc", 100),
+            record(1, "2", "abc", 100),
+            record(2, "1", "abc", 100),
+            record(1, "1", "def", 105),
+            record(1, "2", "def", 300),
+            // Only benchmarked at the head revision, so not compared
+            record(3, "1", "def", 100),
+            // Latest record for a part wins
+            record(1, "1", "def", 109),
+        ];
+        let comparisons = compare(&records, "abc", "def");
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].head_ns, 109);
+        assert!(!comparisons[0].regressed(10.0));
+        assert!(comparisons[1].regressed(10.0));
+        assert_eq!(comparisons[1].change_percent(), 200.0);
+    }
+}
@@ -0,0 +1,181 @@
+//! Sealing puzzle inputs so they can be committed, and unsealing them again
+//!
+//! `aoc inputs seal` writes `input.txt.sealed` next to each `input.txt`;
+//! `aoc inputs unseal` does the reverse, for a fresh checkout on a machine
+//! with the key. Days read sealed inputs directly, so unsealing is only
+//! needed to look at or edit an input. See [`common::sealed`].
+
+use common::sealed::{Key, key_path, load_key, seal, sealed_path, unseal};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// What happened to one day's input
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Sealed,
+    Unsealed,
+    /// Both copies already hold the same input
+    Unchanged,
+    /// Nothing was written, for the given reason
+    Skipped(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Sealed => write!(f, "sealed"),
+            Outcome::Unsealed => write!(f, "unsealed"),
+            Outcome::Unchanged => write!(f, "unchanged"),
+            Outcome::Skipped(reason) => write!(f, "skipped, {}", reason),
+        }
+    }
+}
+
+/// The configured key, or a new one saved to `.aoc-key` (and `true`)
+pub fn key_for_sealing() -> Result<(Key, bool), String> {
+    if let Some(key) = load_key().map_err(|e| e.to_string())? {
+        return Ok((key, false));
+    }
+    let key = Key::generate();
+    let path = key_path();
+    fs::write(&path, format!("{}\n", key.to_hex()))
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("failed to restrict {}: {}", path.display(), e))?;
+    }
+    Ok((key, true))
+}
+
+/// The key needed to unseal, which must already exist
+pub fn key_for_unsealing() -> Result<Key, String> {
+    load_key().map_err(|e| e.to_string())?.ok_or_else(|| {
+        format!(
+            "no key: set ${} or put it in {}",
+            common::sealed::KEY_ENV,
+            key_path().display()
+        )
+    })
+}
+
+fn read(path: &Path) -> Result<Option<Vec<u8>>, String> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("failed to read {}: {}", path.display(), e)),
+    }
+}
+
+fn write(path: &Path, contents: &[u8]) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Seal `input.txt` in a day's input directory
+///
+/// A sealed copy that already holds the same input is left alone unless
+/// `force` is set, as every sealing produces different bytes and would show
+/// up as a change in git.
+pub fn seal_input(key: &Key, dir: &Path, force: bool) -> Result<Outcome, String> {
+    let path = dir.join("input.txt");
+    let input = match read(&path)? {
+        Some(input) if !input.trim_ascii().is_empty() => input,
+        _ => return Ok(Outcome::Skipped("no input.txt".to_string())),
+    };
+    let sealed = sealed_path(&path);
+    if !force
+        && let Some(existing) = read(&sealed)?
+        && unseal(key, &existing).is_ok_and(|existing| existing == input)
+    {
+        return Ok(Outcome::Unchanged);
+    }
+    write(&sealed, &seal(key, &input))?;
+    Ok(Outcome::Sealed)
+}
+
+/// Unseal `input.txt.sealed` in a day's input directory
+///
+/// An `input.txt` with different content is only replaced with `force`.
+pub fn unseal_input(key: &Key, dir: &Path, force: bool) -> Result<Outcome, String> {
+    let path = dir.join("input.txt");
+    let sealed = sealed_path(&path);
+    let Some(bytes) = read(&sealed)? else {
+        return Ok(Outcome::Skipped("no input.txt.sealed".to_string()));
+    };
+    let input = unseal(key, &bytes).map_err(|e| format!("{}: {}", sealed.display(), e))?;
+    match read(&path)? {
+        Some(existing) if existing == input => return Ok(Outcome::Unchanged),
+        Some(existing) if !existing.trim_ascii().is_empty() && !force => {
+            return Ok(Outcome::Skipped(
+                "input.txt differs from the sealed copy; pass --force to replace it".to_string(),
+            ));
+        }
+        _ => {}
+    }
+    write(&path, &input)?;
+    Ok(Outcome::Unsealed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn input_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_seal_and_unseal() {
+        let dir = input_dir("round-trip");
+        let key = Key::generate();
+        assert!(matches!(
+            seal_input(&key, &dir, false),
+            Ok(Outcome::Skipped(_))
+        ));
+
+        fs::write(dir.join("input.txt"), "1 2 3\n").unwrap();
+        assert_eq!(seal_input(&key, &dir, false), Ok(Outcome::Sealed));
+        let sealed = fs::read(dir.join("input.txt.sealed")).unwrap();
+        assert_eq!(seal_input(&key, &dir, false), Ok(Outcome::Unchanged));
+        assert_eq!(fs::read(dir.join("input.txt.sealed")).unwrap(), sealed);
+        // Forcing seals again, with a fresh nonce
+        assert_eq!(seal_input(&key, &dir, true), Ok(Outcome::Sealed));
+        assert_ne!(fs::read(dir.join("input.txt.sealed")).unwrap(), sealed);
+
+        fs::remove_file(dir.join("input.txt")).unwrap();
+        assert_eq!(unseal_input(&key, &dir, false), Ok(Outcome::Unsealed));
+        assert_eq!(
+            fs::read_to_string(dir.join("input.txt")).unwrap(),
+            "1 2 3\n"
+        );
+        assert_eq!(unseal_input(&key, &dir, false), Ok(Outcome::Unchanged));
+    }
+
+    #[test]
+    fn test_unseal_keeps_different_input() {
+        let dir = input_dir("different");
+        let key = Key::generate();
+        fs::write(dir.join("input.txt"), "old\n").unwrap();
+        seal_input(&key, &dir, false).unwrap();
+        fs::write(dir.join("input.txt"), "edited\n").unwrap();
+
+        assert!(matches!(
+            unseal_input(&key, &dir, false),
+            Ok(Outcome::Skipped(_))
+        ));
+        assert_eq!(
+            fs::read_to_string(dir.join("input.txt")).unwrap(),
+            "edited\n"
+        );
+        assert_eq!(unseal_input(&key, &dir, true), Ok(Outcome::Unsealed));
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "old\n");
+
+        assert!(unseal_input(&Key::generate(), &dir, true).is_err());
+    }
+}
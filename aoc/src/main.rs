@@ -0,0 +1,482 @@
+//! Runner for every day of Advent of Code 2025
+
+mod client;
+mod extract;
+mod fetch;
+mod history;
+mod inputs;
+#[cfg(test)]
+mod mock_server;
+mod registry;
+mod scaffold;
+mod selection;
+mod submit;
+mod table;
+
+use common::bench::Budget;
+use common::{Answer, InputError, ParseError, git_revision, try_read_input, workspace_root};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use table::Table;
+
+const USAGE: &str = "\
+Usage: aoc <command> [args]
+
+Commands:
+  run <days>    Run days and print a table of answers and timings
+                <days> is a day (5), a range (1..=12, 3..6), a list (1,3) or 'all'
+  bench <days>  Benchmark days and append the results to the history file
+  compare [--threshold <percent>] [<base> [<head>]]
+                Compare median timings between two benchmarked revisions and
+                fail if any part got slower than the threshold (default 10%).
+                <head> defaults to the latest revision in the history and
+                <base> to the one benchmarked before it
+  new <day>     Create a crate for a new day from template/ and register it
+  fetch <days>  Download puzzle inputs into dayNN/input/input.txt, skipping
+                days that already have one
+  examples [--force] <day> <page.html>
+                Write the examples and answers from a saved puzzle page into
+                dayNN/input/, leaving example files that have content unless
+                --force is given
+  inputs seal [--force] [<days>]
+                Encrypt each input.txt into input.txt.sealed for committing,
+                creating a key in .aoc-key if none is set. Sealed copies of
+                the same input are kept unless --force is given
+  inputs unseal [--force] [<days>]
+                Decrypt each input.txt.sealed into input.txt, leaving inputs
+                that differ unless --force is given
+  submit [--force] <day> <part>
+                Solve one part and submit the answer. Answers already judged
+                wrong are never sent again, and guesses that contradict an
+                earlier \"too high\" or \"too low\" only with --force
+  help          Show this message
+
+The history is kept in bench-history.csv at the workspace root, or in
+$AOC_BENCH_HISTORY if set. Downloads use the session cookie in $AOC_SESSION
+or .aoc-session at the workspace root. Submissions are logged to
+submissions.csv at the workspace root, or to $AOC_SUBMISSIONS if set. Sealed
+inputs use the key in $AOC_INPUT_KEY or .aoc-key at the workspace root.";
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("compare") => compare(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("examples") => examples(&args[1..]),
+        Some("inputs") => inputs_command(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("help" | "-h" | "--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Short description of why a day could not be run, for the table
+fn input_problem(error: &InputError) -> &'static str {
+    match error {
+        InputError::NotFound { .. } => "missing input",
+        InputError::Empty { .. } => "empty input",
+        InputError::NoKey { .. } | InputError::Unseal { .. } => "sealed input",
+        _ => "unreadable input",
+    }
+}
+
+/// Read a day's input and hand it to `f`
+///
+/// Problems are reported on stderr and summarised for the table.
+fn with_input<T>(
+    day: u8,
+    f: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, &'static str> {
+    let input = try_read_input(day).map_err(|e| {
+        eprintln!("Day {:02}: {}", day, e);
+        input_problem(&e)
+    })?;
+    f(&input).map_err(|e| {
+        eprintln!("Day {:02}: bad input: {}", day, e);
+        "bad input"
+    })
+}
+
+/// A table row for a day that could not be run, with `problem` in the last column
+fn problem_row(day: u8, columns: usize, problem: &str) -> Vec<String> {
+    let mut row = vec![day.to_string()];
+    row.extend((2..columns).map(|_| "-".to_string()));
+    row.push(problem.to_string());
+    row
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let [spec] = args else {
+        return Err("Usage: aoc run <days>".to_string());
+    };
+    let days = selection::parse_days(spec)?;
+
+    let mut table = Table::new(&[
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Parse",
+        "Time (Part 1)",
+        "Time (Part 2)",
+        "Total",
+    ]);
+    let mut total = Duration::ZERO;
+    let mut failures = 0;
+
+    for &day in &days {
+        let entry = registry::find(day).expect("selection only yields registered days");
+        match with_input(day, entry.run) {
+            Ok(result) => {
+                let day_total = result.total_time();
+                total += day_total;
+                table.push(vec![
+                    day.to_string(),
+                    result.part1.to_string(),
+                    result.part2.to_string(),
+                    format!("{:?}", result.parse_time),
+                    format!("{:?}", result.part1_time),
+                    format!("{:?}", result.part2_time),
+                    format!("{:?}", day_total),
+                ]);
+            }
+            Err(problem) => {
+                failures += 1;
+                table.push(problem_row(day, 7, problem));
+            }
+        }
+    }
+
+    print!("{}", table);
+    println!();
+    println!("Total time: {:?}", total);
+    if failures > 0 {
+        return Err(format!(
+            "{} of {} days could not be run",
+            failures,
+            days.len()
+        ));
+    }
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let [spec] = args else {
+        return Err("Usage: aoc bench <days>".to_string());
+    };
+    let days = selection::parse_days(spec)?;
+    let revision = git_revision().unwrap_or_else(|| history::UNKNOWN_REVISION.to_string());
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let path = history::default_path();
+
+    let mut table = Table::new(&["Day", "Parse", "Part 1", "Part 2", "Runs"]);
+    let mut records = Vec::new();
+    for day in days {
+        let entry = registry::find(day).expect("selection only yields registered days");
+        let report = match with_input(day, |input| (entry.bench)(input, &Budget::default())) {
+            Ok(report) => report,
+            Err(problem) => {
+                table.push(problem_row(day, 5, problem));
+                continue;
+            }
+        };
+        table.push(vec![
+            day.to_string(),
+            format!("{:.2?}", report.parse.median),
+            format!("{:.2?}", report.part1.median),
+            format!("{:.2?}", report.part2.median),
+            report.part1.runs.min(report.part2.runs).to_string(),
+        ]);
+        records.extend(history::Record::from_report(
+            day, &revision, timestamp, &report,
+        ));
+    }
+
+    print!("{}", table);
+    println!();
+    history::append(&path, &records)
+        .map_err(|e| format!("cannot write history {}: {}", path.display(), e))?;
+    println!(
+        "Recorded {} results for {} in {}",
+        records.len(),
+        revision,
+        path.display()
+    );
+    Ok(())
+}
+
+fn compare(args: &[String]) -> Result<(), String> {
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut revisions = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--threshold" {
+            let value = args.next().ok_or("--threshold needs a percentage")?;
+            threshold = value
+                .trim_end_matches('%')
+                .parse()
+                .map_err(|_| format!("invalid threshold '{}'", value))?;
+        } else {
+            revisions.push(arg.as_str());
+        }
+    }
+
+    let path = history::default_path();
+    let records = history::load(&path)?;
+    let known = history::revisions(&records);
+    let (base, head) = match revisions[..] {
+        [] => match known[..] {
+            [.., base, head] => (base, head),
+            _ => return Err("need benchmarks from two revisions to compare".to_string()),
+        },
+        [base] => match known.last() {
+            Some(&head) => (base, head),
+            None => return Err("the benchmark history is empty".to_string()),
+        },
+        [base, head] => (base, head),
+        _ => {
+            return Err("Usage: aoc compare [--threshold <percent>] [<base> [<head>]]".to_string());
+        }
+    };
+    for revision in [base, head] {
+        if !known.contains(&revision) {
+            return Err(format!("no benchmarks recorded for revision {}", revision));
+        }
+    }
+
+    for revision in [base, head] {
+        if revision.ends_with("-dirty") {
+            eprintln!(
+                "warning: {} was benchmarked with uncommitted changes",
+                revision
+            );
+        }
+    }
+
+    let comparisons = history::compare(&records, base, head);
+    let mut table = Table::new(&["Day", "Part", base, head, "Change", ""]);
+    let mut regressions = 0;
+    for c in &comparisons {
+        let regressed = c.regressed(threshold);
+        regressions += regressed as usize;
+        table.push(vec![
+            c.day.to_string(),
+            c.part.clone(),
+            format!("{:.2?}", Duration::from_nanos(c.base_ns as u64)),
+            format!("{:.2?}", Duration::from_nanos(c.head_ns as u64)),
+            format!("{:+.1}%", c.change_percent()),
+            if regressed { "REGRESSED" } else { "" }.to_string(),
+        ]);
+    }
+    print!("{}", table);
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} of {} parts regressed by more than {}%",
+            regressions,
+            comparisons.len(),
+            threshold
+        ));
+    }
+    println!();
+    println!("No regressions beyond {}%", threshold);
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+    let [day] = args else {
+        return Err("Usage: aoc new <day>".to_string());
+    };
+    let day: u8 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    let root = workspace_root();
+    for path in scaffold::new_day(root, day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    let name = format!("day{:02}", day);
+    println!();
+    println!("Next steps:");
+    println!(
+        "  1. Add the example to {0}/input/example.txt and its answers to examples.toml",
+        name
+    );
+    println!(
+        "  2. Add your puzzle input to {0}/input/input.txt, or run: aoc fetch {1}",
+        name, day
+    );
+    println!("  3. Implement the solution in {}/src/lib.rs", name);
+    println!("  4. Run with: cargo run -p {}", name);
+    println!("  5. Test with: cargo test -p {}", name);
+    Ok(())
+}
+
+fn fetch(args: &[String]) -> Result<(), String> {
+    let [spec] = args else {
+        return Err("Usage: aoc fetch <days>".to_string());
+    };
+    let days = selection::parse_days(spec)?;
+    let root = workspace_root();
+    let client = client::Client::from_env(root).map_err(|e| e.to_string())?;
+    for day in days {
+        match fetch::fetch_input(&client, root, day)? {
+            fetch::Fetched::Cached(path) => {
+                println!("Day {:02}: already have {}", day, path.display())
+            }
+            fetch::Fetched::Downloaded(path) => {
+                println!("Day {:02}: saved {}", day, path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+fn submit(args: &[String]) -> Result<(), String> {
+    const SUBMIT_USAGE: &str = "Usage: aoc submit [--force] <day> <part>";
+    let force = args.iter().any(|arg| arg == "--force");
+    let args: Vec<&String> = args.iter().filter(|arg| *arg != "--force").collect();
+    let [day, part] = args[..] else {
+        return Err(SUBMIT_USAGE.to_string());
+    };
+    let day: u8 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    let part: u8 = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(format!("invalid part '{}': expected 1 or 2", part)),
+    };
+    let entry = registry::find(day).ok_or_else(|| format!("day {} is not registered", day))?;
+
+    let result = with_input(day, |input| (entry.run_parts)(input, Some(part)))
+        .map_err(|problem| format!("Day {:02}: {}", day, problem))?;
+    let (_, answer, _) = result.parts()[0];
+    if *answer == Answer::Unsolved {
+        return Err(format!("Day {:02} part {} is not solved", day, part));
+    }
+    let answer = answer.to_string();
+
+    let root = workspace_root();
+    let client = client::Client::from_env(root).map_err(|e| e.to_string())?;
+    let verdict = submit::submit_answer(
+        &client,
+        &submit::default_path(root),
+        day,
+        part,
+        &answer,
+        force,
+    )?;
+    let message = format!("Day {:02} part {}: {} is {}", day, part, answer, verdict);
+    match verdict {
+        submit::Verdict::Correct => {
+            println!("{}", message);
+            Ok(())
+        }
+        _ => Err(message),
+    }
+}
+
+fn examples(args: &[String]) -> Result<(), String> {
+    const EXAMPLES_USAGE: &str = "Usage: aoc examples [--force] <day> <page.html>";
+    let force = args.iter().any(|arg| arg == "--force");
+    let args: Vec<&String> = args.iter().filter(|arg| *arg != "--force").collect();
+    let [day, page] = args[..] else {
+        return Err(EXAMPLES_USAGE.to_string());
+    };
+    let day: u8 = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    let root = workspace_root();
+    let dir = root.join(format!("day{:02}", day)).join("input");
+    if !dir.exists() {
+        return Err(format!(
+            "{} does not exist; create the day with `aoc new {}`",
+            dir.display(),
+            day
+        ));
+    }
+
+    let html = std::fs::read_to_string(page).map_err(|e| format!("cannot read {}: {}", page, e))?;
+    let examples = extract::extract_examples(&html)?;
+    for path in extract::write_examples(&dir, &examples, force)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    for page_example in &examples {
+        let expected = &page_example.example.expected;
+        println!(
+            "  {}: part 1 = {}, part 2 = {}",
+            page_example.example.name,
+            expected.part(1).unwrap_or("-"),
+            expected.part(2).unwrap_or("-")
+        );
+    }
+    println!("Check them against the puzzle text before relying on them.");
+    Ok(())
+}
+
+fn inputs_command(args: &[String]) -> Result<(), String> {
+    const INPUTS_USAGE: &str =
+        "Usage: aoc inputs seal [--force] [<days>] | aoc inputs unseal [--force] [<days>]";
+    let force = args.iter().any(|arg| arg == "--force");
+    let args: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|arg| *arg != "--force")
+        .collect();
+    let (command, spec) = match args[..] {
+        [command] => (command, "all"),
+        [command, spec] => (command, spec),
+        _ => return Err(INPUTS_USAGE.to_string()),
+    };
+    let days = selection::parse_days(spec)?;
+    let root = workspace_root();
+    let input_dir = |day: u8| root.join(format!("day{:02}", day)).join("input");
+
+    match command {
+        "seal" => {
+            let (key, created) = inputs::key_for_sealing()?;
+            if created {
+                println!(
+                    "Created a key in {}; keep a copy somewhere safe, as the sealed inputs cannot be read without it",
+                    common::sealed::key_path().display()
+                );
+            }
+            for day in days {
+                println!(
+                    "Day {:02}: {}",
+                    day,
+                    inputs::seal_input(&key, &input_dir(day), force)?
+                );
+            }
+        }
+        "unseal" => {
+            let key = inputs::key_for_unsealing()?;
+            for day in days {
+                println!(
+                    "Day {:02}: {}",
+                    day,
+                    inputs::unseal_input(&key, &input_dir(day), force)?
+                );
+            }
+        }
+        _ => return Err(INPUTS_USAGE.to_string()),
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Every day crate linked into the runner
+
+use common::bench::{BenchReport, Budget, bench_day};
+use common::{DayResult, ParseError, Solution, run_day, run_parts};
+
+/// A registered day's solution
+pub struct Entry {
+    pub day: u8,
+    pub run: fn(&str) -> Result<DayResult, ParseError>,
+    /// Run one part only, or both with `None`
+    pub run_parts: fn(&str, Option<u8>) -> Result<DayResult, ParseError>,
+    pub bench: fn(&str, &Budget) -> Result<BenchReport, ParseError>,
+}
+
+impl Entry {
+    const fn of<S: Solution>() -> Self {
+        Entry {
+            day: S::DAY,
+            run: run_day::<S>,
+            run_parts: run_parts::<S>,
+            bench: bench_day::<S>,
+        }
+    }
+}
+
+/// All registered days, in calendar order
+pub const DAYS: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day02::Day02>(),
+    Entry::of::<day03::Day03>(),
+    Entry::of::<day04::Day04>(),
+    Entry::of::<day05::Day05>(),
+    Entry::of::<day06::Day06>(),
+    Entry::of::<day07::Day07>(),
+    Entry::of::<day08::Day08>(),
+    Entry::of::<day09::Day09>(),
+    Entry::of::<day10::Day10>(),
+    Entry::of::<day11::Day11>(),
+    Entry::of::<day12::Day12>(),
+];
+
+/// Look up a registered day
+pub fn find(day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{normalize_input, read_examples, try_read_named_example};
+
+    #[test]
+    fn test_days_in_calendar_order() {
+        let days: Vec<u8> = DAYS.iter().map(|entry| entry.day).collect();
+        let expected: Vec<u8> = (1..=DAYS.len() as u8).collect();
+        assert_eq!(days, expected);
+    }
+
+    #[test]
+    fn test_windows_line_endings_solve_like_unix() {
+        for entry in DAYS {
+            for example in read_examples(entry.day) {
+                let unix = try_read_named_example(entry.day, &example.name).unwrap();
+                let windows = format!("\u{feff}{}", unix.replace('\n', "\r\n"));
+                let windows = normalize_input(windows);
+                assert_eq!(windows, unix, "day {:02} {}", entry.day, example.name);
+
+                // Run the parts the example has answers for, as check_examples does
+                let expected = &example.expected;
+                let only = match (&expected.part1, &expected.part2) {
+                    (Some(_), None) => Some(1),
+                    (None, Some(_)) => Some(2),
+                    _ => None,
+                };
+                let result = (entry.run_parts)(&windows, only)
+                    .unwrap_or_else(|e| panic!("day {:02} {}: {}", entry.day, example.name, e));
+                for (part, answer, _) in result.parts() {
+                    if let Some(expected) = expected.part(part) {
+                        assert_eq!(
+                            answer.to_string(),
+                            expected,
+                            "day {:02} {} part {}",
+                            entry.day,
+                            example.name,
+                            part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! Creating a new day crate from `template/`
+//!
+//! `aoc new <day>` copies the templates, adds the crate to the workspace
+//! members and dependencies, makes the runner depend on it and registers it
+//! in [`crate::registry`]. Every change is worked out before anything is
+//! written, so a day that already exists anywhere leaves the tree untouched.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, InlineTable, Item, Table, Value};
+
+/// Templates, relative to `template/`, and where they go in the new crate
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml.template", "Cargo.toml"),
+    ("lib.rs.template", "src/lib.rs"),
+    ("main.rs.template", "src/main.rs"),
+    ("README.md.template", "README.md"),
+    ("examples.toml.template", "input/examples.toml"),
+];
+
+/// Empty files for the example and puzzle input
+const PLACEHOLDERS: &[&str] = &["input/example.txt", "input/input.txt"];
+
+const REGISTRY: &str = "aoc/src/registry.rs";
+
+/// Fill in `{{DAY}}` (zero-padded) and `{{DAY_NUM}}`
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY_NUM}}", &day.to_string())
+        .replace("{{DAY}}", &format!("{:02}", day))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+fn parse_manifest(path: &Path) -> Result<DocumentMut, String> {
+    read(path)?
+        .parse()
+        .map_err(|e| format!("{} is not valid TOML: {}", path.display(), e))
+}
+
+/// Sort key keeping `dayNN` entries in order after everything else
+fn day_key(name: &str) -> Option<&str> {
+    name.strip_prefix("day")
+        .filter(|n| n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Sort a table's day entries by day, leaving the other entries first
+fn sort_days(table: &mut Table) {
+    table.sort_values_by(|a, _, b, _| day_key(a.get()).cmp(&day_key(b.get())));
+}
+
+/// Add the crate to `workspace.members` and `workspace.dependencies`
+fn add_to_workspace(manifest: &mut DocumentMut, name: &str) -> Result<(), String> {
+    let members = manifest
+        .get_mut("workspace")
+        .and_then(|w| w.get_mut("members"))
+        .and_then(Item::as_array_mut)
+        .ok_or("workspace Cargo.toml has no workspace.members array")?;
+    if members.iter().any(|m| m.as_str() == Some(name)) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+    // After the last member that sorts before it, formatted like that member
+    let index = members
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.as_str().is_some_and(|m| m < name))
+        .last()
+        .map_or(0, |(i, _)| i + 1);
+    let like = members.get(index.saturating_sub(1)).or(members.get(index));
+    let mut member = Value::from(name);
+    if let Some(like) = like {
+        *member.decor_mut() = like.decor().clone();
+    }
+    members.insert_formatted(index, member);
+
+    let dependencies = manifest
+        .get_mut("workspace")
+        .and_then(|w| w.get_mut("dependencies"))
+        .and_then(Item::as_table_mut)
+        .ok_or("workspace Cargo.toml has no [workspace.dependencies] table")?;
+    if dependencies.contains_key(name) {
+        return Err(format!("{} is already a workspace dependency", name));
+    }
+    let mut path = InlineTable::new();
+    path.insert("path", name.into());
+    dependencies.insert(name, Item::Value(Value::InlineTable(path)));
+    sort_days(dependencies);
+    Ok(())
+}
+
+/// Make the runner depend on the crate as `dayNN.workspace = true`
+fn add_to_runner(manifest: &mut DocumentMut, name: &str) -> Result<(), String> {
+    let dependencies = manifest
+        .get_mut("dependencies")
+        .and_then(Item::as_table_mut)
+        .ok_or("aoc/Cargo.toml has no [dependencies] table")?;
+    if dependencies.contains_key(name) {
+        return Err(format!("the runner already depends on {}", name));
+    }
+    let mut workspace = Table::new();
+    workspace.set_dotted(true);
+    workspace.insert("workspace", toml_edit::value(true));
+    dependencies.insert(name, Item::Table(workspace));
+    sort_days(dependencies);
+    Ok(())
+}
+
+/// Add `Entry::of::<dayNN::DayNN>()` to the registry's `DAYS`, in day order
+fn add_to_registry(source: &str, day: u8) -> Result<String, String> {
+    let entry = format!("Entry::of::<day{:02}::Day{:02}>(),", day, day);
+    let start = source
+        .find("pub const DAYS")
+        .ok_or("registry has no DAYS list")?;
+    let end = start
+        + source[start..]
+            .find("\n];")
+            .ok_or("registry's DAYS list is not closed")?;
+    let list = &source[start..end];
+    if list.contains(&entry) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    // Insert before the first entry for a later day, or at the end
+    let mut at = end + 1;
+    let mut offset = start;
+    for line in list.split_inclusive('\n') {
+        if let Some(existing) = line
+            .trim()
+            .strip_prefix("Entry::of::<day")
+            .and_then(|rest| rest.get(..2))
+            .and_then(|n| n.parse::<u8>().ok())
+            && existing > day
+        {
+            at = offset;
+            break;
+        }
+        offset += line.len();
+    }
+    Ok(format!("{}    {}\n{}", &source[..at], entry, &source[at..]))
+}
+
+/// Create day `day` in the workspace at `root`, returning the files written
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not between 1 and 25", day));
+    }
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let mut workspace = parse_manifest(&root.join("Cargo.toml"))?;
+    add_to_workspace(&mut workspace, &name)?;
+    let mut runner = parse_manifest(&root.join("aoc/Cargo.toml"))?;
+    add_to_runner(&mut runner, &name)?;
+    let registry = add_to_registry(&read(&root.join(REGISTRY))?, day)?;
+
+    let mut files = Vec::new();
+    for (template, target) in TEMPLATES {
+        let template = read(&root.join("template").join(template))?;
+        files.push((dir.join(target), fill(&template, day)));
+    }
+    for placeholder in PLACEHOLDERS {
+        files.push((dir.join(placeholder), String::new()));
+    }
+    files.push((root.join("Cargo.toml"), workspace.to_string()));
+    files.push((root.join("aoc/Cargo.toml"), runner.to_string()));
+    files.push((root.join(REGISTRY), registry));
+
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::workspace_root;
+
+    const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = [
+    "aoc",
+    "common",
+    "day01",
+    "day02",
+    "day04",
+]
+
+[workspace.dependencies]
+common = { path = "common" }
+day01 = { path = "day01" }
+day02 = { path = "day02" }
+day04 = { path = "day04" }
+"#;
+
+    const RUNNER: &str = "[dependencies]\ncommon.workspace = true\nday01.workspace = true\nday02.workspace = true\nday04.workspace = true\n";
+
+    const REGISTRY_SOURCE: &str = "pub const DAYS: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day02::Day02>(),
+    Entry::of::<day04::Day04>(),
+];
+";
+
+    /// A workspace with days 1, 2 and 4 and the real templates
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(&root.join("Cargo.toml"), WORKSPACE).unwrap();
+        write(&root.join("aoc/Cargo.toml"), RUNNER).unwrap();
+        write(&root.join(REGISTRY), REGISTRY_SOURCE).unwrap();
+        for (template, _) in TEMPLATES {
+            let contents = read(&workspace_root().join("template").join(template)).unwrap();
+            write(&root.join("template").join(template), &contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_new_day_fills_in_templates() {
+        let root = workspace("templates");
+        let files = new_day(&root, 3).unwrap();
+        assert!(files.contains(&root.join("day03/input/input.txt")));
+
+        let lib = read(&root.join("day03/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day03;") && lib.contains("const DAY: u8 = 3;"));
+        let manifest = read(&root.join("day03/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day03\""));
+        assert!(!read(&root.join("day03/README.md")).unwrap().contains("{{"));
+        assert_eq!(read(&root.join("day03/input/example.txt")).unwrap(), "");
+    }
+
+    #[test]
+    fn test_new_day_registers_in_order() {
+        let root = workspace("order");
+        new_day(&root, 3).unwrap();
+
+        let workspace = read(&root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("    \"day02\",\n    \"day03\",\n    \"day04\",\n]"));
+        assert!(workspace.contains(
+            "day02 = { path = \"day02\" }\nday03 = { path = \"day03\" }\nday04 = { path = \"day04\" }"
+        ));
+        let runner = read(&root.join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains("day02.workspace = true\nday03.workspace = true\nday04"));
+        let registry = read(&root.join(REGISTRY)).unwrap();
+        assert!(registry.contains(
+            "<day02::Day02>(),\n    Entry::of::<day03::Day03>(),\n    Entry::of::<day04::Day04>()"
+        ));
+
+        new_day(&root, 5).unwrap();
+        let registry = read(&root.join(REGISTRY)).unwrap();
+        assert!(registry.ends_with("    Entry::of::<day05::Day05>(),\n];\n"));
+    }
+
+    #[test]
+    fn test_new_day_refuses_existing_days() {
+        let root = workspace("existing");
+        // The crate exists
+        fs::create_dir_all(root.join("day02")).unwrap();
+        assert!(new_day(&root, 2).unwrap_err().contains("already exists"));
+
+        // Only the manifest knows about the day: nothing may be written
+        let before = read(&root.join(REGISTRY)).unwrap();
+        assert!(
+            new_day(&root, 4)
+                .unwrap_err()
+                .contains("already a workspace member")
+        );
+        assert!(!root.join("day04").exists());
+        assert_eq!(read(&root.join(REGISTRY)).unwrap(), before);
+
+        assert!(new_day(&root, 26).is_err());
+    }
+}
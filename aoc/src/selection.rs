@@ -0,0 +1,68 @@
+//! Parsing which days to run from the command line
+
+use crate::registry::{self, DAYS};
+
+/// Parse a day selection such as `5`, `1..=12`, `3..6`, `all` or `1,4,7..=9`
+///
+/// Days are returned in the order given, without duplicates.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for part in spec.split(',') {
+        for day in parse_part(part.trim())? {
+            if registry::find(day).is_none() {
+                return Err(format!("day {} is not registered", day));
+            }
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+    }
+    Ok(days)
+}
+
+fn parse_part(part: &str) -> Result<Vec<u8>, String> {
+    if part == "all" {
+        return Ok(DAYS.iter().map(|entry| entry.day).collect());
+    }
+    if let Some((start, end)) = part.split_once("..=") {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        return Ok((start..=end).collect());
+    }
+    if let Some((start, end)) = part.split_once("..") {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        return Ok((start..end).collect());
+    }
+    Ok(vec![parse_day(part)?])
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.trim().parse().map_err(|_| format!("invalid day '{}'", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_day() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(parse_days("1..=3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("3..6"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("2,1..=3"), Ok(vec![2, 1, 3]));
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(parse_days("all").unwrap().len(), DAYS.len());
+    }
+
+    #[test]
+    fn test_rejects_unknown_days() {
+        assert!(parse_days("99").is_err());
+        assert!(parse_days("x").is_err());
+    }
+}
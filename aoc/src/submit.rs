@@ -0,0 +1,457 @@
+//! Submitting answers and remembering how they were judged
+//!
+//! Every submission is appended to a CSV log. A wrong guess costs a wait
+//! before the next one, so an answer the log already knows to be wrong is
+//! never sent again, and one that contradicts an earlier "too high" or "too
+//! low" is only sent when forced.
+
+use crate::client::Client;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Environment variable overriding where the log is kept
+pub const LOG_ENV: &str = "AOC_SUBMISSIONS";
+
+const HEADER: &str = "day,part,timestamp,verdict,answer";
+
+/// How the site judged an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// Sent too soon after a wrong answer; nothing was judged
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the answer was judged and found wrong
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn to_csv(self) -> String {
+        match self {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too high".to_string(),
+            Verdict::TooLow => "too low".to_string(),
+            Verdict::Wrong => "wrong".to_string(),
+            Verdict::RateLimited { wait } => format!("rate limited {}s", wait.as_secs()),
+            Verdict::WrongLevel => "wrong level".to_string(),
+        }
+    }
+
+    fn from_csv(field: &str) -> Option<Verdict> {
+        Some(match field {
+            "correct" => Verdict::Correct,
+            "too high" => Verdict::TooHigh,
+            "too low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "wrong level" => Verdict::WrongLevel,
+            _ => {
+                let secs = field.strip_prefix("rate limited ")?.strip_suffix('s')?;
+                Verdict::RateLimited {
+                    wait: Duration::from_secs(secs.parse().ok()?),
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait } => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "part already solved or not unlocked"),
+        }
+    }
+}
+
+/// Read the verdict from the page the site returns for a submission
+pub fn parse_verdict(page: &str) -> Option<Verdict> {
+    // Only the message itself, not the navigation around it
+    let start = page.find("<article")?;
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |e| start + e);
+    let message = &page[start..end];
+
+    if message.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if message.contains("You gave an answer too recently") {
+        Some(Verdict::RateLimited {
+            wait: parse_wait(message).unwrap_or(Duration::ZERO),
+        })
+    } else if message.contains("your answer is too high") {
+        Some(Verdict::TooHigh)
+    } else if message.contains("your answer is too low") {
+        Some(Verdict::TooLow)
+    } else if message.contains("That's not the right answer") {
+        Some(Verdict::Wrong)
+    } else if message.contains("You don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// The wait in "You have 1m 5s left to wait"
+fn parse_wait(message: &str) -> Option<Duration> {
+    let end = message.find(" left to wait")?;
+    let start = message[..end].rfind("You have ")? + "You have ".len();
+    let mut secs = 0;
+    for amount in message[start..end].split_whitespace() {
+        let (n, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// One logged submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl Submission {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.timestamp,
+            self.verdict.to_csv(),
+            self.answer
+        )
+    }
+
+    fn from_csv(line: &str) -> Result<Submission, String> {
+        // The answer comes last so it may contain commas
+        let fields: Vec<&str> = line.splitn(5, ',').collect();
+        let [day, part, timestamp, verdict, answer] = fields[..] else {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        };
+        fn num<T: std::str::FromStr>(field: &str) -> Result<T, String> {
+            field
+                .parse()
+                .map_err(|_| format!("invalid number '{}'", field))
+        }
+        Ok(Submission {
+            day: num(day)?,
+            part: num(part)?,
+            timestamp: num(timestamp)?,
+            verdict: Verdict::from_csv(verdict)
+                .ok_or_else(|| format!("unknown verdict '{}'", verdict))?,
+            answer: answer.to_string(),
+        })
+    }
+}
+
+/// `$AOC_SUBMISSIONS`, or `submissions.csv` at the workspace root
+pub fn default_path(root: &Path) -> PathBuf {
+    match std::env::var_os(LOG_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => root.join("submissions.csv"),
+    }
+}
+
+/// Read every submission, oldest first; a missing log is empty
+pub fn load(path: &Path) -> Result<Vec<Submission>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+    };
+    contents
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| line != HEADER && !line.trim().is_empty())
+        .map(|(i, line)| {
+            Submission::from_csv(line).map_err(|e| format!("{}:{}: {}", path.display(), i + 1, e))
+        })
+        .collect()
+}
+
+/// Append a submission, writing the header first if the log is new
+pub fn append(path: &Path, submission: &Submission) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    writeln!(file, "{}", submission.to_csv())
+}
+
+/// Check a guess against earlier submissions for the same part
+///
+/// Answers already judged are refused outright; the returned warnings are
+/// guesses the earlier hints already rule out.
+pub fn check_guess(
+    log: &[Submission],
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Vec<String>, String> {
+    let earlier = log.iter().filter(|s| s.day == day && s.part == part);
+    let mut warnings = Vec::new();
+    for submission in earlier {
+        if submission.verdict == Verdict::Correct {
+            return Err(format!(
+                "day {} part {} was already solved with {}",
+                day, part, submission.answer
+            ));
+        }
+        if submission.answer == answer && submission.verdict.is_wrong() {
+            return Err(format!(
+                "{} was already submitted and is {}",
+                answer, submission.verdict
+            ));
+        }
+        let (Ok(guess), Ok(bound)) = (answer.parse::<i128>(), submission.answer.parse::<i128>())
+        else {
+            continue;
+        };
+        match submission.verdict {
+            Verdict::TooHigh if guess >= bound => {
+                warnings.push(format!(
+                    "{} is not below {}, which was too high",
+                    guess, bound
+                ));
+            }
+            Verdict::TooLow if guess <= bound => {
+                warnings.push(format!(
+                    "{} is not above {}, which was too low",
+                    guess, bound
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(warnings)
+}
+
+/// Check, submit and log an answer
+///
+/// Guesses with warnings are not sent unless `force` is set.
+pub fn submit_answer(
+    client: &Client,
+    log_path: &Path,
+    day: u8,
+    part: u8,
+    answer: &str,
+    force: bool,
+) -> Result<Verdict, String> {
+    let warnings = check_guess(&load(log_path)?, day, part, answer)?;
+    if !warnings.is_empty() && !force {
+        return Err(format!(
+            "not submitted, {}; pass --force to submit anyway",
+            warnings.join(", ")
+        ));
+    }
+
+    let page = client
+        .answer(day, part, answer)
+        .map_err(|e| format!("Day {:02}: {}", day, e))?;
+    let verdict = parse_verdict(&page)
+        .ok_or_else(|| format!("Day {:02}: could not understand the response", day))?;
+    let submission = Submission {
+        day,
+        part,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        verdict,
+        answer: answer.to_string(),
+    };
+    append(log_path, &submission)
+        .map_err(|e| format!("cannot write {}: {}", log_path.display(), e))?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><header>[Log Out]</header><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn submission(part: u8, verdict: Verdict, answer: &str) -> Submission {
+        Submission {
+            day: 3,
+            part,
+            timestamp: 1733200000,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer to decorating the North Pole.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Verdict::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Verdict::RateLimited {
+                    wait: Duration::from_secs(65),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(parse_verdict(&page(message)), Some(verdict), "{}", message);
+        }
+        assert_eq!(parse_verdict("<html>Something else</html>"), None);
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(load(&path), Ok(Vec::new()));
+
+        let submissions = [
+            submission(1, Verdict::TooLow, "100"),
+            submission(
+                1,
+                Verdict::RateLimited {
+                    wait: Duration::from_secs(30),
+                },
+                "120",
+            ),
+            submission(2, Verdict::Correct, "a,b"),
+        ];
+        for s in &submissions {
+            append(&path, s).unwrap();
+        }
+        assert_eq!(load(&path).unwrap(), submissions);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_check_guess() {
+        let log = [
+            submission(1, Verdict::TooLow, "100"),
+            submission(1, Verdict::TooHigh, "200"),
+            submission(1, Verdict::Wrong, "150"),
+            submission(
+                1,
+                Verdict::RateLimited {
+                    wait: Duration::ZERO,
+                },
+                "160",
+            ),
+            submission(2, Verdict::Correct, "7"),
+        ];
+        assert_eq!(check_guess(&log, 3, 1, "170"), Ok(vec![]));
+        assert_eq!(check_guess(&log, 3, 1, "160"), Ok(vec![]));
+        assert!(
+            check_guess(&log, 3, 1, "150")
+                .unwrap_err()
+                .contains("already submitted")
+        );
+        assert!(check_guess(&log, 3, 1, "100").is_err());
+        assert_eq!(check_guess(&log, 3, 1, "250").unwrap().len(), 1);
+        assert_eq!(check_guess(&log, 3, 1, "50").unwrap().len(), 1);
+        assert!(
+            check_guess(&log, 3, 2, "8")
+                .unwrap_err()
+                .contains("already solved")
+        );
+        // Other days and non-numeric answers are unaffected
+        assert_eq!(check_guess(&log, 4, 1, "150"), Ok(vec![]));
+        assert_eq!(check_guess(&log, 3, 1, "abc"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let path = std::env::temp_dir().join(format!("aoc-submit-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let server = MockServer::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=50" => "That's not the right answer; your answer is too low.",
+                "level=1&answer=60" => {
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait."
+                }
+                _ => "That's the right answer!",
+            };
+            (200, page(message))
+        });
+        let client = Client::new(&server.url, "abc123");
+
+        assert_eq!(
+            submit_answer(&client, &path, 3, 1, "50", false),
+            Ok(Verdict::TooLow)
+        );
+        // Known wrong, and below a "too low": neither is sent
+        assert!(submit_answer(&client, &path, 3, 1, "50", true).is_err());
+        assert!(submit_answer(&client, &path, 3, 1, "40", false).is_err());
+        assert_eq!(server.requests().len(), 1);
+
+        assert_eq!(
+            submit_answer(&client, &path, 3, 1, "60", false),
+            Ok(Verdict::RateLimited {
+                wait: Duration::from_secs(42)
+            })
+        );
+        assert_eq!(
+            submit_answer(&client, &path, 3, 1, "70", false),
+            Ok(Verdict::Correct)
+        );
+        assert!(submit_answer(&client, &path, 3, 1, "80", false).is_err());
+        assert_eq!(server.requests().len(), 3);
+
+        let verdicts: Vec<Verdict> = load(&path).unwrap().iter().map(|s| s.verdict).collect();
+        assert_eq!(verdicts.len(), 3);
+        assert_eq!(verdicts[2], Verdict::Correct);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+//! Rendering results as an aligned Markdown table
+
+use std::fmt;
+
+/// A table of text cells, rendered with columns padded to equal width
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Table {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.header.len(), "row width mismatch");
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.header.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths
+    }
+}
+
+fn write_row(f: &mut fmt::Formatter<'_>, cells: &[String], widths: &[usize]) -> fmt::Result {
+    write!(f, "|")?;
+    for (cell, &width) in cells.iter().zip(widths) {
+        write!(f, " {:width$} |", cell, width = width)?;
+    }
+    writeln!(f)
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        write_row(f, &self.header, &widths)?;
+        let separator: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+        write_row(f, &separator, &widths)?;
+        for row in &self.rows {
+            write_row(f, row, &widths)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_aligns_columns() {
+        let mut table = Table::new(&["Day", "Answer"]);
+        table.push(vec!["1".to_string(), "12345".to_string()]);
+        assert_eq!(
+            table.to_string(),
+            "| Day | Answer |\n| --- | ------ |\n| 1   | 12345  |\n"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Puzzle answers of any type
+
+use std::fmt;
+
+/// The answer to one part of a puzzle
+///
+/// Integers compare by value regardless of signedness, so `Signed(5)` equals
+/// `Unsigned(5)`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    /// The part has not been solved (or has no puzzle)
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                u128::try_from(*a) == Ok(*b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $wide:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Signed, i128: i8, i16, i32, i64, i128, isize);
+impl_from_int!(Unsigned, u128: u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("EZCL").to_string(), "EZCL");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_integers_compare_by_value() {
+        assert_eq!(Answer::from(5i64), Answer::from(5usize));
+        assert_ne!(Answer::from(-5i64), Answer::from(5u64));
+        assert_ne!(Answer::from(5u64), Answer::from("5"));
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(Answer::from(None::<u64>), Answer::Unsolved);
+        assert_eq!(Answer::from(Some(7u8)), Answer::Unsigned(7));
+    }
+}
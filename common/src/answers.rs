@@ -0,0 +1,201 @@
+//! Known answers for puzzle inputs, looked up by a hash of the input
+//!
+//! Everyone's puzzle input is different, so rather than hardcoding one
+//! account's answers, each day keeps an `answers.toml` next to its inputs:
+//!
+//! ```toml
+//! [3f2a9c01d4e5b678]
+//! part1 = 1105
+//! part2 = 6599
+//! ```
+//!
+//! Section names are the [`input_hash`] of an input in hex. Tests for an
+//! input that is missing, or whose answers are not listed, are skipped.
+
+use crate::input::try_read_day_file;
+use crate::parse::{ParseError, Span};
+use crate::testing::{skip_test, test_input};
+use crate::{Solution, run_parts};
+
+/// File listing known answers, next to the day's inputs
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers for both parts, as displayed; either may be unknown
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// The expected answer to part 1 or 2
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Parse `[name]` sections of `part1 = ...` and `part2 = ...` lines
+///
+/// Blank lines and lines starting with `#` are ignored, and answers may be
+/// quoted.
+pub(crate) fn parse_manifest(text: &str) -> Result<Vec<(Span<'_>, Expected)>, ParseError> {
+    let mut sections: Vec<(Span, Expected)> = Vec::new();
+    for line in Span::new(text).lines() {
+        let line = line.trim();
+        if line.is_empty() || line.as_str().starts_with('#') {
+            continue;
+        }
+        if line.as_str().starts_with('[') {
+            let (name, rest) = line.group('[')?;
+            let name = name.trim();
+            if name.is_empty() || !rest.is_blank() {
+                return Err(line.error("expected '[name]'"));
+            }
+            if sections.iter().any(|(n, _)| n.as_str() == name.as_str()) {
+                return Err(name.error(format!("'{}' is listed twice", name.as_str())));
+            }
+            sections.push((name, Expected::default()));
+            continue;
+        }
+
+        let (key, value) = line.split_once("=")?;
+        let (_, expected) = sections
+            .last_mut()
+            .ok_or_else(|| key.error("expected '[name]' before the answers"))?;
+        let answer = match key.trim().as_str() {
+            "part1" => &mut expected.part1,
+            "part2" => &mut expected.part2,
+            _ => return Err(key.error("expected 'part1' or 'part2'")),
+        };
+        let value = value.trim().as_str();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        *answer = Some(value.to_string());
+    }
+    Ok(sections)
+}
+
+/// 64-bit FNV-1a hash of the input, which is stable across platforms and
+/// Rust versions unlike `std`'s hasher
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Parse an answers file into input hashes and their answers
+pub fn parse_answers(text: &str) -> Result<Vec<(u64, Expected)>, ParseError> {
+    parse_manifest(text)?
+        .into_iter()
+        .map(|(name, expected)| {
+            let hash = u64::from_str_radix(name.as_str(), 16)
+                .map_err(|_| name.error("expected an input hash in hex"))?;
+            Ok((hash, expected))
+        })
+        .collect()
+}
+
+/// Known answers for an input of the given day, if any are listed
+///
+/// Panics if the day's answers file is malformed.
+pub fn known_answers(day: u8, input: &str) -> Option<Expected> {
+    let text = try_read_day_file(day, ANSWERS_FILE).ok()?;
+    let answers = parse_answers(&text)
+        .unwrap_or_else(|e| panic!("Bad {} for day {:02}: {}", ANSWERS_FILE, day, e));
+    let hash = input_hash(input);
+    answers
+        .into_iter()
+        .find(|&(h, _)| h == hash)
+        .map(|(_, expected)| expected)
+}
+
+/// Check one part against the known answer for the puzzle input, skipping
+/// with a message when there is no input or its answer is not known
+pub fn check_answer<S: Solution>(part: u8) {
+    let test = format!("part {}", part);
+    let Some(input) = test_input(S::DAY, &test) else {
+        return;
+    };
+    let expected = known_answers(S::DAY, &input);
+    let Some(expected) = expected.as_ref().and_then(|e| e.part(part)) else {
+        let reason = format!(
+            "no known answer for input {:016x} in {}",
+            input_hash(&input),
+            ANSWERS_FILE
+        );
+        skip_test(S::DAY, &test, &reason);
+        return;
+    };
+
+    let result = run_parts::<S>(&input, Some(part))
+        .unwrap_or_else(|e| panic!("Day {:02} bad input: {}", S::DAY, e));
+    let (_, answer, _) = result.parts()[0];
+    assert_eq!(
+        answer.to_string(),
+        expected,
+        "Day {:02} part {}",
+        S::DAY,
+        part
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        // Reference values for 64-bit FNV-1a
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(input_hash("foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let text = "# comment\n[00000000000000ff]\npart1 = 5\npart2 = \"a b\"\n\n[1a]\npart2=2\n";
+        assert_eq!(
+            parse_answers(text),
+            Ok(vec![
+                (
+                    0xff,
+                    Expected {
+                        part1: Some("5".to_string()),
+                        part2: Some("a b".to_string()),
+                    }
+                ),
+                (
+                    0x1a,
+                    Expected {
+                        part1: None,
+                        part2: Some("2".to_string()),
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        let err = parse_answers("part1 = 5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse_answers("[ff]\npart3 = 1").unwrap_err();
+        assert_eq!(err.message, "expected 'part1' or 'part2'");
+        let err = parse_answers("[ff]\n[ff]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse_answers("[example]").unwrap_err();
+        assert_eq!(err.message, "expected an input hash in hex");
+        assert!(parse_answers("[ff\n").is_err());
+    }
+
+    #[test]
+    fn test_unknown_input_has_no_answers() {
+        assert_eq!(known_answers(1, "not anyone's puzzle input\n"), None);
+    }
+}
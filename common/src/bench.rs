@@ -0,0 +1,195 @@
+//! Statistical benchmarking of solutions
+//!
+//! Each phase is warmed up, then run repeatedly until the time budget is
+//! spent, and summarised as min/median/mean/p95/stddev over all samples.
+
+use crate::{ParseError, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long to spend benchmarking each phase
+#[derive(Debug, Clone)]
+pub struct Budget {
+    /// Time spent running the phase before measuring
+    pub warmup: Duration,
+    /// Time spent collecting samples once warmed up
+    pub measure: Duration,
+    /// Samples to collect even if the time budget is exceeded
+    pub min_runs: usize,
+    /// Stop after this many samples even if time remains
+    pub max_runs: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget {
+            warmup: Duration::from_millis(200),
+            measure: Duration::from_secs(2),
+            min_runs: 5,
+            max_runs: 100_000,
+        }
+    }
+}
+
+/// Summary statistics over a set of timing samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise samples; panics if there are none
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Benchmark a closure within the given budget
+pub fn measure<T, F: FnMut() -> T>(budget: &Budget, mut f: F) -> Stats {
+    let warmup_start = Instant::now();
+    loop {
+        black_box(f());
+        if warmup_start.elapsed() >= budget.warmup {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let measure_start = Instant::now();
+    while samples.len() < budget.max_runs.max(1)
+        && (samples.len() < budget.min_runs || measure_start.elapsed() < budget.measure)
+    {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// Benchmark results for each phase of a day
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchReport {
+    pub fn print(&self, day: u8) {
+        println!("=== Day {:02} (benchmark) ===", day);
+        for (name, stats) in [
+            ("Parse", &self.parse),
+            ("Part 1", &self.part1),
+            ("Part 2", &self.part2),
+        ] {
+            println!(
+                "{:<7} min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  p95 {:>10.2?}  stddev {:>10.2?}  ({} runs)",
+                format!("{}:", name),
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.p95,
+                stats.stddev,
+                stats.runs
+            );
+        }
+    }
+}
+
+/// Benchmark parsing and both parts of a day's solution
+///
+/// Fails without benchmarking anything if the input does not parse.
+pub fn bench_day<S: Solution>(input: &str, budget: &Budget) -> Result<BenchReport, ParseError> {
+    let parsed = S::parse(input)?;
+    let parse = measure(budget, || S::parse(input));
+    let part1 = measure(budget, || S::part1(&parsed));
+    let part2 = measure(budget, || S::part2(&parsed));
+
+    Ok(BenchReport {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [5, 1, 3, 2, 4].map(ms).to_vec();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(3));
+        assert_eq!(stats.p95, ms(5));
+        // Sample standard deviation of 1..=5 is sqrt(2.5)
+        assert!((stats.stddev.as_secs_f64() - 2.5f64.sqrt() / 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_even_median() {
+        let stats = Stats::from_samples(&[ms(1), ms(2), ms(3), ms(10)]);
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn test_measure_respects_run_limits() {
+        let budget = Budget {
+            warmup: Duration::ZERO,
+            measure: Duration::ZERO,
+            min_runs: 3,
+            max_runs: 10,
+        };
+        assert_eq!(measure(&budget, || 1 + 1).runs, 3);
+
+        let budget = Budget {
+            measure: Duration::from_secs(60),
+            ..budget
+        };
+        assert_eq!(measure(&budget, || 1 + 1).runs, 10);
+    }
+}
@@ -0,0 +1,225 @@
+//! Command-line handling shared by every day binary
+
+use crate::bench::{Budget, bench_day};
+use crate::format::Format;
+use crate::input::{
+    INPUT_ARG, InputError, STDIN_PATH, check_whitespace, try_read_example, try_read_input,
+    try_read_input_from_path, try_read_named_example, try_read_stdin,
+};
+use crate::{Solution, run_parts};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Options:
+  --input <path>    Read the puzzle input from <path>, or from stdin if <path> is -
+  -                 Read the puzzle input from stdin
+  --example [name]  Use the day's example, or its example file <name>.txt
+  --part <1|2>      Run only one part
+  --bench           Benchmark parsing and both parts instead of a single run
+  --format <fmt>    Output format for a single run: text (default), json or csv
+  --check-input     Warn about trailing whitespace in the input
+  -h, --help        Show this message";
+
+/// Where a day binary reads its input from
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's own puzzle input, looked up as usual
+    #[default]
+    Puzzle,
+    Path(PathBuf),
+    Stdin,
+    /// The day's example, or the named one
+    Example(Option<String>),
+}
+
+impl Source {
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            Source::Puzzle => try_read_input(day),
+            Source::Path(path) => try_read_input_from_path(path),
+            Source::Stdin => try_read_stdin(),
+            Source::Example(None) => try_read_example(day),
+            Source::Example(Some(name)) => try_read_named_example(day, name),
+        }
+    }
+}
+
+/// Options accepted by a day binary
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub source: Source,
+    /// Run only this part, 1 or 2
+    pub part: Option<u8>,
+    pub bench: bool,
+    pub format: Format,
+    pub check_input: bool,
+    pub help: bool,
+}
+
+fn path_source(path: String) -> Source {
+    if path == STDIN_PATH {
+        Source::Stdin
+    } else {
+        Source::Path(PathBuf::from(path))
+    }
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("--part must be 1 or 2, not '{}'", part)),
+    }
+}
+
+impl Args {
+    /// Parse arguments, not including the program name
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut sources = Vec::new();
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => parsed.bench = true,
+                "--check-input" => parsed.check_input = true,
+                "-h" | "--help" => parsed.help = true,
+                STDIN_PATH => sources.push(Source::Stdin),
+                INPUT_ARG => {
+                    let path = args.next().ok_or("--input needs a path")?;
+                    sources.push(path_source(path));
+                }
+                "--example" => {
+                    // The name is optional, so only take a value that is not an option
+                    let name = args.next_if(|next| !next.starts_with('-'));
+                    sources.push(Source::Example(name));
+                }
+                "--part" => {
+                    let part = args.next().ok_or("--part needs 1 or 2")?;
+                    parsed.part = Some(parse_part(&part)?);
+                }
+                "--format" => {
+                    let format = args.next().ok_or("--format needs a value")?;
+                    parsed.format = format.parse()?;
+                }
+                _ => {
+                    if let Some(path) = arg.strip_prefix("--input=") {
+                        sources.push(path_source(path.to_string()));
+                    } else if let Some(name) = arg.strip_prefix("--example=") {
+                        sources.push(Source::Example(Some(name.to_string())));
+                    } else if let Some(part) = arg.strip_prefix("--part=") {
+                        parsed.part = Some(parse_part(part)?);
+                    } else if let Some(format) = arg.strip_prefix("--format=") {
+                        parsed.format = format.parse()?;
+                    } else {
+                        return Err(format!("unknown argument '{}'", arg));
+                    }
+                }
+            }
+        }
+        if sources.len() > 1 {
+            return Err("give at most one of --input, --example and -".to_string());
+        }
+        if parsed.bench && parsed.part.is_some() {
+            return Err("--bench always runs both parts, so --part cannot be used".to_string());
+        }
+        if parsed.bench && parsed.format != Format::Text {
+            return Err(
+                "--bench only prints a text report, so --format cannot be used".to_string(),
+            );
+        }
+        parsed.source = sources.pop().unwrap_or_default();
+        Ok(parsed)
+    }
+}
+
+/// Entry point for a day binary: parse arguments, load input, run or benchmark
+pub fn run_cli<S: Solution>() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        println!("Usage: day{:02} [options]\n\n{}", S::DAY, USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let input = match args.source.read(S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {:02}: {}", S::DAY, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.check_input {
+        for issue in check_whitespace(&input) {
+            eprintln!("Day {:02}: warning: {}", S::DAY, issue);
+        }
+    }
+
+    let result = if args.bench {
+        bench_day::<S>(&input, &Budget::default()).map(|report| report.print(S::DAY))
+    } else {
+        run_parts::<S>(&input, args.part).map(|result| result.print_as(args.format))
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Day {:02}: bad input: {}", S::DAY, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        let args = parse(&["--bench", "--input", "in.txt"]).unwrap();
+        assert!(args.bench);
+        assert_eq!(args.source, Source::Path(PathBuf::from("in.txt")));
+        assert_eq!(
+            parse(&["--input=x.txt"]).unwrap().source,
+            Source::Path(PathBuf::from("x.txt"))
+        );
+        assert_eq!(parse(&["--format", "csv"]).unwrap().format, Format::Csv);
+        assert!(parse(&["--check-input"]).unwrap().check_input);
+        assert_eq!(parse(&["--part", "2"]).unwrap().part, Some(2));
+        assert_eq!(parse(&["--part=1"]).unwrap().part, Some(1));
+    }
+
+    #[test]
+    fn test_parse_sources() {
+        assert_eq!(parse(&["-"]).unwrap().source, Source::Stdin);
+        assert_eq!(parse(&["--input", "-"]).unwrap().source, Source::Stdin);
+        assert_eq!(
+            parse(&["--example", "--part", "1"]).unwrap().source,
+            Source::Example(None)
+        );
+        let named = Source::Example(Some("example2".to_string()));
+        assert_eq!(parse(&["--example", "example2"]).unwrap().source, named);
+        assert_eq!(parse(&["--example=example2"]).unwrap().source, named);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--frobnicate"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part", "1", "--bench"]).is_err());
+        assert!(parse(&["--bench", "--format", "json"]).is_err());
+        assert!(parse(&["--bench", "--format=text"]).is_ok());
+        assert!(parse(&["--example", "-"]).is_err());
+    }
+}
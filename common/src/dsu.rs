@@ -0,0 +1,218 @@
+//! Disjoint-set union (union-find) over the elements `0..n`
+//!
+//! Roots are found iteratively with path compression and sets are merged by
+//! size. A DSU made with [`Dsu::with_rollback`] skips path compression so
+//! that unions can be undone back to an earlier [`Dsu::snapshot`].
+
+/// A union that can be undone: `child` was attached under `root`
+#[derive(Debug, Clone, Copy)]
+struct Merge {
+    root: usize,
+    child: usize,
+}
+
+/// Disjoint sets of the elements `0..n`
+#[derive(Debug, Clone)]
+pub struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    /// Unions in the order they happened, in rollback mode only
+    history: Option<Vec<Merge>>,
+}
+
+impl Dsu {
+    /// `n` singleton sets
+    pub fn new(n: usize) -> Self {
+        Dsu {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            history: None,
+        }
+    }
+
+    /// `n` singleton sets whose unions can be rolled back
+    pub fn with_rollback(n: usize) -> Self {
+        Dsu {
+            history: Some(Vec::new()),
+            ..Dsu::new(n)
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Representative of the set containing `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        if self.history.is_none() {
+            let mut x = x;
+            while self.parent[x] != root {
+                let next = self.parent[x];
+                self.parent[x] = root;
+                x = next;
+            }
+        }
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`; false if they were already one set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root, mut child) = (self.find(a), self.find(b));
+        if root == child {
+            return false;
+        }
+        if self.size[root] < self.size[child] {
+            std::mem::swap(&mut root, &mut child);
+        }
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.components -= 1;
+        if let Some(history) = &mut self.history {
+            history.push(Merge { root, child });
+        }
+        true
+    }
+
+    /// Whether `a` and `b` are in the same set
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set containing `x`
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Size of every set, in no particular order
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// Elements of every set, each in ascending order, sets ordered by
+    /// their smallest element
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index_of_root[root] == usize::MAX {
+                index_of_root[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[index_of_root[root]].push(x);
+        }
+        groups
+    }
+
+    /// Elements in the same set as `x`, in ascending order
+    pub fn members(&mut self, x: usize) -> Vec<usize> {
+        let root = self.find(x);
+        (0..self.len()).filter(|&y| self.find(y) == root).collect()
+    }
+
+    /// Point to roll back to; panics unless made with [`Dsu::with_rollback`]
+    pub fn snapshot(&self) -> usize {
+        self.history
+            .as_ref()
+            .expect("snapshot needs a DSU made with Dsu::with_rollback")
+            .len()
+    }
+
+    /// Undo every union made since `snapshot` was taken
+    pub fn rollback(&mut self, snapshot: usize) {
+        let history = self
+            .history
+            .as_mut()
+            .expect("rollback needs a DSU made with Dsu::with_rollback");
+        assert!(snapshot <= history.len(), "snapshot is from the future");
+        for Merge { root, child } in history.drain(snapshot..).rev() {
+            self.parent[child] = child;
+            self.size[root] -= self.size[child];
+            self.components += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut dsu = Dsu::new(5);
+        assert_eq!(dsu.components(), 5);
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(3, 4));
+        assert!(dsu.union(1, 4));
+        assert!(!dsu.union(0, 3));
+        assert!(dsu.same(0, 4));
+        assert!(!dsu.same(0, 2));
+        assert_eq!(dsu.components(), 2);
+        assert_eq!(dsu.size_of(3), 4);
+        assert_eq!(dsu.component_sizes(), vec![4, 1]);
+    }
+
+    #[test]
+    fn test_groups_and_members() {
+        let mut dsu = Dsu::new(6);
+        dsu.union(5, 1);
+        dsu.union(2, 4);
+        dsu.union(4, 0);
+        assert_eq!(dsu.groups(), vec![vec![0, 2, 4], vec![1, 5], vec![3]]);
+        assert_eq!(dsu.members(5), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Union by size keeps trees shallow, so link a chain by hand to check
+        // that finding the root of a deep one does not overflow the stack
+        let n = 1_000_000;
+        let mut dsu = Dsu::new(n);
+        for i in 1..n {
+            dsu.parent[i] = i - 1;
+        }
+        dsu.size[0] = n;
+        dsu.components = 1;
+        assert_eq!(dsu.find(n - 1), 0);
+        // Path compression pointed the whole chain straight at the root
+        assert!((1..n).all(|i| dsu.parent[i] == 0));
+        assert_eq!(dsu.size_of(n - 1), n);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut dsu = Dsu::with_rollback(4);
+        dsu.union(0, 1);
+        let snapshot = dsu.snapshot();
+        dsu.union(2, 3);
+        dsu.union(1, 3);
+        assert_eq!(dsu.components(), 1);
+
+        dsu.rollback(snapshot);
+        assert_eq!(dsu.components(), 3);
+        assert!(dsu.same(0, 1));
+        assert!(!dsu.same(2, 3));
+        assert_eq!(dsu.size_of(0), 2);
+        assert_eq!(dsu.groups(), vec![vec![0, 1], vec![2], vec![3]]);
+    }
+}
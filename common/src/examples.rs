@@ -0,0 +1,127 @@
+//! Example inputs and the answers the puzzle text gives for them
+//!
+//! Each day lists its examples in an `examples.toml` kept with the example
+//! files, and found the same way:
+//!
+//! ```toml
+//! # From the puzzle text
+//! [example]
+//! part1 = 5
+//!
+//! [example2]
+//! part2 = 2
+//! ```
+//!
+//! Section names are the example files without `.txt`. Either answer may be
+//! left out for examples that only illustrate one part.
+
+use crate::answers::{Expected, parse_manifest};
+use crate::input::{InputError, try_read_day_file, try_read_named_example};
+use crate::parse::ParseError;
+use crate::testing::skip_test;
+use crate::{Solution, run_parts};
+
+/// File listing a day's examples, next to the example inputs
+pub const EXAMPLES_FILE: &str = "examples.toml";
+
+/// An example input and its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub expected: Expected,
+}
+
+/// Parse an examples manifest
+pub fn parse_examples(manifest: &str) -> Result<Vec<Example>, ParseError> {
+    Ok(parse_manifest(manifest)?
+        .into_iter()
+        .map(|(name, expected)| Example {
+            name: name.as_str().to_string(),
+            expected,
+        })
+        .collect())
+}
+
+/// Read a day's examples manifest, panicking on failure
+pub fn read_examples(day: u8) -> Vec<Example> {
+    let manifest = try_read_day_file(day, EXAMPLES_FILE)
+        .unwrap_or_else(|e| panic!("Failed to read {} for day {:02}: {}", EXAMPLES_FILE, day, e));
+    parse_examples(&manifest)
+        .unwrap_or_else(|e| panic!("Bad {} for day {:02}: {}", EXAMPLES_FILE, day, e))
+}
+
+/// Run every example in the day's manifest, panicking with all wrong answers
+///
+/// An empty example without answers, as `aoc new` leaves it, is skipped.
+pub fn check_examples<S: Solution>() {
+    let examples = read_examples(S::DAY);
+    assert!(!examples.is_empty(), "Day {:02} lists no examples", S::DAY);
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let expected = &example.expected;
+        let input = match try_read_named_example(S::DAY, &example.name) {
+            Ok(input) => input,
+            Err(e @ InputError::Empty { .. })
+                if expected.part1.is_none() && expected.part2.is_none() =>
+            {
+                skip_test(S::DAY, "test_examples", &e.to_string());
+                continue;
+            }
+            Err(e) => panic!("Failed to read example '{}': {}", example.name, e),
+        };
+        // Only run the parts with answers, as the others may not apply
+        let only = match (&expected.part1, &expected.part2) {
+            (Some(_), None) => Some(1),
+            (None, Some(_)) => Some(2),
+            _ => None,
+        };
+        let result = match run_parts::<S>(&input, only) {
+            Ok(result) => result,
+            Err(e) => {
+                failures.push(format!("{}: bad input: {}", example.name, e));
+                continue;
+            }
+        };
+        for (part, answer, _) in result.parts() {
+            if let Some(expected) = expected.part(part)
+                && answer.to_string() != expected
+            {
+                failures.push(format!(
+                    "{} part {}: got {}, expected {}",
+                    example.name, part, answer, expected
+                ));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Day {:02} examples failed:\n  {}",
+        S::DAY,
+        failures.join("\n  ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_examples() {
+        let manifest = "# comment\n[example]\npart1 = 5\n\n[example2]\npart2 = 2\n";
+        let examples = parse_examples(manifest).unwrap();
+        let names: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["example", "example2"]);
+        assert_eq!(examples[1].expected.part(1), None);
+        assert_eq!(examples[1].expected.part(2), Some("2"));
+        assert!(parse_examples("[example]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_every_day_lists_its_examples() {
+        // Day crates check their answers; here just make sure each manifest parses
+        for day in 1..=12 {
+            assert!(!read_examples(day).is_empty(), "day {:02}", day);
+        }
+    }
+}
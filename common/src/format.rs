@@ -0,0 +1,196 @@
+//! Machine-readable output of day results
+
+use crate::input::workspace_root;
+use crate::{Answer, DayResult};
+use std::fmt::Write;
+use std::process::Command;
+use std::str::FromStr;
+
+/// How results are written to stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// Header row matching [`DayResult::to_csv`]
+pub const CSV_HEADER: &str = "day,part,answer,duration_ns,parse_ns,revision";
+
+/// The commit the workspace is checked out at, as `git describe` names it
+///
+/// Uncommitted changes add a `-dirty` suffix, as the results may not be
+/// reproducible from the commit alone.
+pub fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(workspace_root())
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let revision = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!revision.is_empty()).then_some(revision)
+}
+
+fn json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn json_answer(out: &mut String, answer: &Answer) {
+    // Answers are strings so that large integers survive JSON parsers using doubles
+    match answer {
+        Answer::Unsolved => out.push_str("null"),
+        answer => json_string(out, &answer.to_string()),
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl DayResult {
+    /// Serialize as a JSON object; durations are in nanoseconds
+    pub fn to_json(&self, revision: Option<&str>) -> String {
+        let mut out = String::new();
+        write!(out, "{{\"day\":{},\"revision\":", self.day).unwrap();
+        match revision {
+            Some(rev) => json_string(&mut out, rev),
+            None => out.push_str("null"),
+        }
+        write!(
+            out,
+            ",\"parse_ns\":{},\"parts\":[",
+            self.parse_time.as_nanos()
+        )
+        .unwrap();
+        for (i, (part, answer, time)) in self.parts().into_iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, "{{\"part\":{},\"answer\":", part).unwrap();
+            json_answer(&mut out, answer);
+            write!(out, ",\"duration_ns\":{}}}", time.as_nanos()).unwrap();
+        }
+        write!(out, "],\"total_ns\":{}}}", self.total_time().as_nanos()).unwrap();
+        out
+    }
+
+    /// Serialize as CSV rows, one per part, without the [`CSV_HEADER`]
+    pub fn to_csv(&self, revision: Option<&str>) -> String {
+        let mut out = String::new();
+        for (part, answer, time) in self.parts() {
+            let answer = match answer {
+                Answer::Unsolved => String::new(),
+                answer => answer.to_string(),
+            };
+            writeln!(
+                out,
+                "{},{},{},{},{},{}",
+                self.day,
+                part,
+                csv_field(&answer),
+                time.as_nanos(),
+                self.parse_time.as_nanos(),
+                csv_field(revision.unwrap_or(""))
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// Print in the given format
+    pub fn print_as(&self, format: Format) {
+        match format {
+            Format::Text => self.print(),
+            Format::Json => println!("{}", self.to_json(git_revision().as_deref())),
+            Format::Csv => print!("{}\n{}", CSV_HEADER, self.to_csv(git_revision().as_deref())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result() -> DayResult {
+        DayResult {
+            day: 3,
+            part1: Answer::from(42u64),
+            part2: Answer::from("a,\"b\""),
+            parse_time: Duration::from_nanos(10),
+            part1_time: Duration::from_nanos(200),
+            part2_time: Duration::from_nanos(3000),
+            only: None,
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            result().to_json(Some("abc123")),
+            r#"{"day":3,"revision":"abc123","parse_ns":10,"parts":[{"part":1,"answer":"42","duration_ns":200},{"part":2,"answer":"a,\"b\"","duration_ns":3000}],"total_ns":3210}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut unsolved = result();
+        unsolved.part2 = Answer::Unsolved;
+        assert_eq!(unsolved.to_csv(None), "3,1,42,200,10,\n3,2,,3000,10,\n");
+        assert_eq!(
+            result().to_csv(Some("abc123")).lines().nth(1),
+            Some("3,2,\"a,\"\"b\"\"\",3000,10,abc123")
+        );
+    }
+
+    #[test]
+    fn test_single_part() {
+        let mut second = result();
+        second.only = Some(2);
+        assert_eq!(second.to_csv(None), "3,2,\"a,\"\"b\"\"\",3000,10,\n");
+        assert!(second.to_json(None).contains(r#""parts":[{"part":2,"#));
+    }
+}
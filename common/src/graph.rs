@@ -0,0 +1,331 @@
+//! Directed graphs over interned node names
+//!
+//! Node names are mapped to dense ids `0..len` once while building, and
+//! adjacency is stored compactly (one offset per node into a shared edge
+//! list), so traversals index vectors instead of hashing strings.
+
+use std::collections::HashMap;
+
+use crate::parse::{ParseError, Span};
+
+/// Dense id of a node, in order of first appearance
+pub type NodeId = usize;
+
+/// Collects named nodes and edges before freezing them into a [`Graph`]
+#[derive(Debug, Default)]
+pub struct GraphBuilder {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<(NodeId, NodeId)>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of the named node, adding it if it is new
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    /// Add a directed edge between two named nodes
+    pub fn edge(&mut self, from: &str, to: &str) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.edges.push((from, to));
+    }
+
+    pub fn build(self) -> Graph {
+        Graph::from_edges(self.names, self.ids, &self.edges)
+    }
+}
+
+/// A directed graph with compact adjacency
+#[derive(Debug, Clone)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// Edges leaving node `n` are `targets[offsets[n]..offsets[n + 1]]`
+    offsets: Vec<usize>,
+    targets: Vec<NodeId>,
+}
+
+impl Graph {
+    fn from_edges(
+        names: Vec<String>,
+        ids: HashMap<String, NodeId>,
+        edges: &[(NodeId, NodeId)],
+    ) -> Graph {
+        let mut offsets = vec![0; names.len() + 1];
+        for &(from, _) in edges {
+            offsets[from + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+        // Place edges stably so each node keeps its edges in insertion order
+        let mut next = offsets.clone();
+        let mut targets = vec![0; edges.len()];
+        for &(from, to) in edges {
+            targets[next[from]] = to;
+            next[from] += 1;
+        }
+        Graph {
+            names,
+            ids,
+            offsets,
+            targets,
+        }
+    }
+
+    /// Parse lines of the form `name: target target ...`
+    ///
+    /// Blank lines are skipped; any other line without `": "` is an error.
+    pub fn parse(input: &str) -> Result<Graph, ParseError> {
+        let mut builder = GraphBuilder::new();
+        for line in Span::new(input).lines().filter(|line| !line.is_blank()) {
+            let (source, targets) = line.split_once(": ")?;
+            let source = source.trim();
+            if source.is_empty() {
+                return Err(line.error("expected a node name before ':'"));
+            }
+            builder.node(source.as_str());
+            for target in targets.words() {
+                builder.edge(source.as_str(), target.as_str());
+            }
+        }
+        Ok(builder.build())
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Id of a named node, if it is in the graph
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// Nodes with an edge from `id`
+    pub fn neighbors(&self, id: NodeId) -> &[NodeId] {
+        &self.targets[self.offsets[id]..self.offsets[id + 1]]
+    }
+
+    /// The same nodes with every edge reversed
+    pub fn reverse(&self) -> Graph {
+        let edges: Vec<(NodeId, NodeId)> = (0..self.len())
+            .flat_map(|from| self.neighbors(from).iter().map(move |&to| (to, from)))
+            .collect();
+        Graph::from_edges(self.names.clone(), self.ids.clone(), &edges)
+    }
+
+    /// Nodes ordered so every edge points forward, or `None` if there is a cycle
+    pub fn toposort(&self) -> Option<Vec<NodeId>> {
+        let mut in_degree = vec![0usize; self.len()];
+        for &to in &self.targets {
+            in_degree[to] += 1;
+        }
+        let mut order: Vec<NodeId> = (0..self.len()).filter(|&n| in_degree[n] == 0).collect();
+        let mut i = 0;
+        while i < order.len() {
+            for &next in self.neighbors(order[i]) {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    order.push(next);
+                }
+            }
+            i += 1;
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Some cycle in the graph, as the nodes along it in edge order
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath,
+            Done,
+        }
+
+        let mut state = vec![State::New; self.len()];
+        for start in 0..self.len() {
+            if state[start] != State::New {
+                continue;
+            }
+            // Depth-first search with an explicit stack of (node, next edge)
+            let mut path = vec![(start, 0)];
+            state[start] = State::OnPath;
+            while let Some(&mut (node, ref mut edge)) = path.last_mut() {
+                let Some(&next) = self.neighbors(node).get(*edge) else {
+                    state[node] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *edge += 1;
+                match state[next] {
+                    State::New => {
+                        state[next] = State::OnPath;
+                        path.push((next, 0));
+                    }
+                    State::OnPath => {
+                        let from = path.iter().position(|&(n, _)| n == next).unwrap();
+                        return Some(path[from..].iter().map(|&(n, _)| n).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.toposort().is_none()
+    }
+
+    /// Which nodes can be reached from `from`, including itself
+    pub fn reachable(&self, from: NodeId) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![from];
+        seen[from] = true;
+        while let Some(node) = stack.pop() {
+            for &next in self.neighbors(node) {
+                if !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Number of distinct paths from `from` to every node
+    ///
+    /// Returns `None` if the graph has a cycle, since counts could be infinite.
+    /// Counts grow exponentially with the depth of a dense DAG, hence `u128`.
+    pub fn path_counts(&self, from: NodeId) -> Option<Vec<u128>> {
+        Some(self.path_counts_in(&self.toposort()?, from))
+    }
+
+    /// Like [`path_counts`](Self::path_counts), reusing an order from
+    /// [`toposort`](Self::toposort) when counting from several nodes
+    pub fn path_counts_in(&self, order: &[NodeId], from: NodeId) -> Vec<u128> {
+        let mut counts = vec![0u128; self.len()];
+        counts[from] = 1;
+        for &node in order {
+            if counts[node] == 0 {
+                continue;
+            }
+            for &next in self.neighbors(node) {
+                counts[next] += counts[node];
+            }
+        }
+        counts
+    }
+
+    /// Number of distinct paths from `from` to `to`, or `None` if the graph has a cycle
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Option<u128> {
+        self.path_counts(from).map(|counts| counts[to])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond() -> Graph {
+        Graph::parse("a: b c\nb: d\nc: d\nd: e").unwrap()
+    }
+
+    #[test]
+    fn test_parse_interns_names() {
+        let graph = diamond();
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.edge_count(), 5);
+        let a = graph.id("a").unwrap();
+        let names: Vec<&str> = graph.neighbors(a).iter().map(|&n| graph.name(n)).collect();
+        assert_eq!(names, vec!["b", "c"]);
+        assert_eq!(graph.id("z"), None);
+
+        let err = Graph::parse("a: b\n\nb c").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn test_toposort_and_cycles() {
+        let graph = diamond();
+        let order = graph.toposort().unwrap();
+        let position = |name| order.iter().position(|&n| n == graph.id(name).unwrap());
+        assert!(position("a") < position("b") && position("b") < position("d"));
+        assert_eq!(graph.find_cycle(), None);
+
+        let cyclic = Graph::parse("a: b\nb: c\nc: a d").unwrap();
+        assert!(cyclic.has_cycle());
+        assert_eq!(cyclic.toposort(), None);
+        let cycle: Vec<&str> = cyclic
+            .find_cycle()
+            .unwrap()
+            .into_iter()
+            .map(|n| cyclic.name(n))
+            .collect();
+        assert_eq!(cycle, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_reachable_and_reverse() {
+        let graph = diamond();
+        let id = |name| graph.id(name).unwrap();
+        let from_b = graph.reachable(id("b"));
+        assert!(from_b[id("e")] && !from_b[id("c")]);
+
+        let reversed = graph.reverse();
+        let to_d = reversed.reachable(id("d"));
+        assert!(to_d[id("a")] && to_d[id("c")] && !to_d[id("e")]);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = diamond();
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(graph.count_paths(id("a"), id("e")), Some(2));
+        assert_eq!(graph.count_paths(id("e"), id("a")), Some(0));
+        let order = graph.toposort().unwrap();
+        assert_eq!(graph.path_counts_in(&order, id("b"))[id("e")], 1);
+        let cyclic = Graph::parse("a: b\nb: a").unwrap();
+        assert_eq!(cyclic.count_paths(0, 1), None);
+    }
+
+    #[test]
+    fn test_count_paths_beyond_u64() {
+        // 70 layers of two nodes, each joined to both nodes of the next
+        let mut builder = GraphBuilder::new();
+        for layer in 0..70 {
+            for from in [format!("a{}", layer), format!("b{}", layer)] {
+                builder.edge(&from, &format!("a{}", layer + 1));
+                builder.edge(&from, &format!("b{}", layer + 1));
+            }
+        }
+        let graph = builder.build();
+        let (from, to) = (graph.id("a0").unwrap(), graph.id("a70").unwrap());
+        assert_eq!(graph.count_paths(from, to), Some(1 << 69));
+    }
+}
@@ -0,0 +1,276 @@
+//! Flat, row-major two-dimensional grid
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position in a grid as `(row, col)`
+pub type Pos = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all eight neighbours, including diagonals
+const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored in a single `Vec`, row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build from rows, padding short rows with `fill` to the widest one
+    pub fn from_rows(rows: Vec<Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse text, converting each character with `f`
+    ///
+    /// Ragged lines are padded with `fill` on the right.
+    pub fn parse_with<F: FnMut(char) -> T>(input: &str, fill: T, mut f: F) -> Self
+    where
+        T: Clone,
+    {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+        Grid::from_rows(rows, fill)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the position lies inside the grid
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// The cell at a position, or `None` outside the grid
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Move from `pos` by `(d_row, d_col)`, or `None` if that leaves the grid
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Orthogonal neighbours of a position that lie inside the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// All eight neighbours of a position that lie inside the grid
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell equal to `value`, row by row
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Number of cells equal to `value`
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.cells.iter().filter(|cell| *cell == value).count()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Cells of one column, top to bottom
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.rows().map(move |row| &row[col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parse text into a character grid, padding ragged lines with spaces
+    pub fn parse(input: &str) -> Self {
+        Grid::parse_with(input, ' ', |c| c)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} outside {}x{} grid", pos, width, height))
+    }
+}
+
+/// Renders one line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse("ab\ncd\nef");
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'c');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "ab\ncd\nef");
+    }
+
+    #[test]
+    fn test_ragged_lines_are_padded() {
+        let grid = Grid::parse("abc\na\nab");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.row(1), &['a', ' ', ' ']);
+        assert_eq!(grid.to_string(), "abc\na  \nab ");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 2)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((2, 2), (0, 1)), None);
+    }
+
+    #[test]
+    fn test_find_and_count() {
+        let mut grid = Grid::parse("..#\n#.S");
+        assert_eq!(grid.find(&'S'), Some((1, 2)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(grid.count(&'#'), 2);
+        grid[(0, 0)] = '#';
+        assert_eq!(grid.count(&'#'), 3);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_with("12\n34", 0, |c| c.to_digit(10).unwrap());
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..]]);
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 3], vec![2, 4]]);
+        assert_eq!(grid.map(|&d| d * 10)[(1, 1)], 40);
+    }
+}
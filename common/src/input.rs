@@ -0,0 +1,436 @@
+//! Loading puzzle inputs and examples from disk
+//!
+//! A day's files are looked up in this order, first match wins:
+//!
+//! 1. `$AOC_INPUT_DIR/dayNN/<file>` when the environment variable is set
+//! 2. `dayNN/input/<file>` next to the day crate's manifest, so binaries and
+//!    tests work from any working directory
+//! 3. `dayNN/input/<file>` and `input/<file>` relative to the current directory
+//!
+//! A path given with `--input` takes precedence in day binaries; that is
+//! decided by [`Source`](crate::Source), not here.
+//!
+//! If no puzzle input is found, the same places are searched for a sealed
+//! `input.txt.sealed`, which is decrypted as it is read; see
+//! [`sealed`](crate::sealed).
+//!
+//! Whatever the source, a UTF-8 byte order mark is dropped and CRLF line
+//! endings become `\n`, so files saved on Windows parse like any other.
+
+use crate::sealed::{Key, SEALED_EXTENSION, SealError, load_key, sealed_path, unseal};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory of inputs laid out as `dayNN/<file>`
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Command-line flag overriding the puzzle input path
+pub const INPUT_ARG: &str = "--input";
+
+/// Path given to `--input` to read standard input instead of a file
+pub const STDIN_PATH: &str = "-";
+
+/// Why an input file could not be loaded
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the candidate paths exist
+    NotFound { tried: Vec<PathBuf> },
+    /// The file exists but we are not allowed to read it
+    PermissionDenied { path: PathBuf },
+    /// The file is not valid UTF-8
+    InvalidUtf8 { path: PathBuf },
+    /// The file exists but contains nothing besides whitespace
+    Empty { path: PathBuf },
+    /// Any other I/O failure
+    Io { path: PathBuf, source: io::Error },
+    /// The input is sealed and there is no key to unseal it
+    NoKey { path: PathBuf },
+    /// The input is sealed and could not be unsealed
+    Unseal { path: PathBuf, source: SealError },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { tried } => {
+                write!(f, "input file not found, tried:")?;
+                for path in tried {
+                    write!(f, "\n  - {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::PermissionDenied { path } => {
+                write!(f, "permission denied reading {}", path.display())
+            }
+            InputError::InvalidUtf8 { path } => {
+                write!(f, "{} is not valid UTF-8", path.display())
+            }
+            InputError::Empty { path } => write!(f, "{} is empty", path.display()),
+            InputError::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            InputError::NoKey { path } => write!(
+                f,
+                "{} is sealed and no key is set in ${} or {}",
+                path.display(),
+                crate::sealed::KEY_ENV,
+                crate::sealed::KEY_FILE
+            ),
+            InputError::Unseal { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            InputError::Unseal { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Strip a leading byte order mark and turn CRLF line endings into `\n`
+pub fn normalize_input(mut content: String) -> String {
+    if content.starts_with('\u{feff}') {
+        content.drain(..'\u{feff}'.len_utf8());
+    }
+    if content.contains('\r') {
+        content = content.replace("\r\n", "\n");
+    }
+    content
+}
+
+/// Whitespace that is easy to save by accident and hard to see
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhitespaceIssue {
+    /// Spaces or tabs at the end of a line, 1-based
+    TrailingWhitespace { line: usize },
+    /// Blank lines after the last line of content
+    TrailingBlankLines { count: usize },
+    /// The last line has no `\n`
+    MissingFinalNewline,
+}
+
+impl fmt::Display for WhitespaceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WhitespaceIssue::TrailingWhitespace { line } => {
+                write!(f, "line {} ends with whitespace", line)
+            }
+            WhitespaceIssue::TrailingBlankLines { count } => {
+                write!(f, "{} blank line(s) at the end", count)
+            }
+            WhitespaceIssue::MissingFinalNewline => write!(f, "no newline at the end"),
+        }
+    }
+}
+
+/// Report trailing whitespace in normalized input
+///
+/// None of these stop a day from parsing, and some puzzles (day 6) pad lines
+/// with spaces on purpose, so this is only run when asked for.
+pub fn check_whitespace(input: &str) -> Vec<WhitespaceIssue> {
+    let mut issues = Vec::new();
+    let lines: Vec<&str> = input.lines().collect();
+    let content_lines = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |last| last + 1);
+    for (i, line) in lines[..content_lines].iter().enumerate() {
+        if line.ends_with([' ', '\t']) {
+            issues.push(WhitespaceIssue::TrailingWhitespace { line: i + 1 });
+        }
+    }
+    if lines.len() > content_lines {
+        issues.push(WhitespaceIssue::TrailingBlankLines {
+            count: lines.len() - content_lines,
+        });
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        issues.push(WhitespaceIssue::MissingFinalNewline);
+    }
+    issues
+}
+
+/// Root of the workspace, recorded when `common` was compiled
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common is a workspace member")
+}
+
+/// Candidate locations of `file` for a given day, in precedence order
+fn candidates(input_dir: Option<&Path>, day: u8, file: &str) -> Vec<PathBuf> {
+    let day_dir = format!("day{:02}", day);
+    let mut paths = Vec::new();
+    if let Some(dir) = input_dir {
+        paths.push(dir.join(&day_dir).join(file));
+    }
+    paths.push(workspace_root().join(&day_dir).join("input").join(file));
+    paths.push(Path::new(&day_dir).join("input").join(file));
+    paths.push(Path::new("input").join(file));
+    paths
+}
+
+/// Candidates for `file`, honouring `AOC_INPUT_DIR`
+fn env_candidates(day: u8, file: &str) -> Vec<PathBuf> {
+    let input_dir = env::var_os(INPUT_DIR_ENV).map(PathBuf::from);
+    candidates(input_dir.as_deref(), day, file)
+}
+
+/// Read the first candidate that exists, reporting every path tried if none do
+fn read_first(candidates: Vec<PathBuf>) -> Result<String, InputError> {
+    for path in &candidates {
+        match try_read_input_from_path(path) {
+            Err(InputError::NotFound { .. }) => continue,
+            result => return result,
+        }
+    }
+    Err(InputError::NotFound { tried: candidates })
+}
+
+/// Like [`read_first`], falling back to sealed copies of the candidates
+fn read_first_or_sealed(candidates: Vec<PathBuf>) -> Result<String, InputError> {
+    let sealed: Vec<PathBuf> = candidates.iter().map(|path| sealed_path(path)).collect();
+    match read_first(candidates) {
+        Err(InputError::NotFound { mut tried }) => match read_first(sealed) {
+            Err(InputError::NotFound { tried: sealed }) => {
+                tried.extend(sealed);
+                Err(InputError::NotFound { tried })
+            }
+            result => result,
+        },
+        result => result,
+    }
+}
+
+/// Read input file for a given day
+pub fn try_read_input(day: u8) -> Result<String, InputError> {
+    read_first_or_sealed(env_candidates(day, "input.txt"))
+}
+
+/// Read example input file for a given day
+pub fn try_read_example(day: u8) -> Result<String, InputError> {
+    try_read_day_file(day, "example.txt")
+}
+
+/// Read any file kept with a day's inputs
+pub(crate) fn try_read_day_file(day: u8, file: &str) -> Result<String, InputError> {
+    read_first(env_candidates(day, file))
+}
+
+/// Read one of a day's other examples, `name` being its file name with or
+/// without the `.txt` extension
+pub fn try_read_named_example(day: u8, name: &str) -> Result<String, InputError> {
+    let file = if name.ends_with(".txt") {
+        name.to_string()
+    } else {
+        format!("{}.txt", name)
+    };
+    try_read_day_file(day, &file)
+}
+
+/// Read all of standard input
+pub fn try_read_stdin() -> Result<String, InputError> {
+    let path = Path::new("<stdin>");
+    let mut bytes = Vec::new();
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|e| InputError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+    decode(path, bytes)
+}
+
+/// Unseal the contents of a sealed `path`, then check and normalize them
+fn decode_sealed(path: &Path, bytes: Vec<u8>, key: Option<&Key>) -> Result<String, InputError> {
+    let key = key.ok_or_else(|| InputError::NoKey {
+        path: path.to_path_buf(),
+    })?;
+    let bytes = unseal(key, &bytes).map_err(|source| InputError::Unseal {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    decode(path, bytes)
+}
+
+/// Read input from a specific path, unsealing it if it is a `.sealed` file
+pub fn try_read_input_from_path<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound {
+            tried: vec![path.to_path_buf()],
+        },
+        io::ErrorKind::PermissionDenied => InputError::PermissionDenied {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        },
+    })?;
+    if path.extension().is_some_and(|ext| ext == SEALED_EXTENSION) {
+        let key = load_key().map_err(|source| InputError::Unseal {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        return decode_sealed(path, bytes, key.as_ref());
+    }
+    decode(path, bytes)
+}
+
+/// Check and normalize the raw contents of `path`
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    let content = String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8 {
+        path: path.to_path_buf(),
+    })?;
+    let content = normalize_input(content);
+    if content.trim().is_empty() {
+        return Err(InputError::Empty {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(content)
+}
+
+/// Read input file for a given day, panicking on failure
+pub fn read_input(day: u8) -> String {
+    try_read_input(day)
+        .unwrap_or_else(|e| panic!("Failed to read input file for day {:02}: {}", day, e))
+}
+
+/// Read example input file for a given day, panicking on failure
+pub fn read_example(day: u8) -> String {
+    try_read_example(day)
+        .unwrap_or_else(|e| panic!("Failed to read example file for day {:02}: {}", day, e))
+}
+
+/// Read input from a specific path, panicking on failure
+pub fn read_input_from_path<P: AsRef<Path>>(path: P) -> String {
+    try_read_input_from_path(path).unwrap_or_else(|e| panic!("Failed to read file: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-common-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_from_path() {
+        let path = temp_file("ok.txt", b"1\n2\n");
+        assert_eq!(try_read_input_from_path(&path).unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn test_crlf_and_bom_are_normalized() {
+        let path = temp_file("windows.txt", b"\xef\xbb\xbf1\r\n\r\n2\r\n");
+        assert_eq!(try_read_input_from_path(&path).unwrap(), "1\n\n2\n");
+        // A lone carriage return is not a line ending and is kept
+        assert_eq!(normalize_input("a\rb\n".to_string()), "a\rb\n");
+    }
+
+    #[test]
+    fn test_check_whitespace() {
+        assert_eq!(check_whitespace("1\n2\n"), vec![]);
+        assert_eq!(
+            check_whitespace("1 \n2\t\n3\n\n\n"),
+            vec![
+                WhitespaceIssue::TrailingWhitespace { line: 1 },
+                WhitespaceIssue::TrailingWhitespace { line: 2 },
+                WhitespaceIssue::TrailingBlankLines { count: 2 },
+            ]
+        );
+        assert_eq!(
+            check_whitespace("1\n2"),
+            vec![WhitespaceIssue::MissingFinalNewline]
+        );
+    }
+
+    #[test]
+    fn test_not_found_lists_candidates() {
+        match read_first(candidates(None, 99, "input.txt")) {
+            Err(InputError::NotFound { tried }) => {
+                assert_eq!(tried, candidates(None, 99, "input.txt"))
+            }
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_input_dir_takes_precedence() {
+        let paths = candidates(Some(Path::new("/inputs")), 5, "input.txt");
+        assert_eq!(paths[0], Path::new("/inputs/day05/input.txt"));
+        assert_eq!(paths[1], workspace_root().join("day05/input/input.txt"));
+    }
+
+    #[test]
+    fn test_reads_day_files_from_any_directory() {
+        // Tests for `common` run from `common/`, where the relative paths miss
+        let example = read_first(candidates(None, 1, "example.txt")).unwrap();
+        assert!(!example.is_empty());
+    }
+
+    #[test]
+    fn test_named_example() {
+        let second = try_read_named_example(11, "example2").unwrap();
+        assert!(second.starts_with("svr:"));
+        assert_eq!(try_read_named_example(11, "example2.txt").unwrap(), second);
+        assert!(matches!(
+            try_read_named_example(11, "no-such-example"),
+            Err(InputError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_empty_file() {
+        let path = temp_file("empty.txt", b" \n\n");
+        assert!(matches!(
+            try_read_input_from_path(&path),
+            Err(InputError::Empty { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let path = temp_file("binary.txt", &[0xff, 0xfe, 0x00]);
+        assert!(matches!(
+            try_read_input_from_path(&path),
+            Err(InputError::InvalidUtf8 { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sealed_input() {
+        let key = Key::generate();
+        let path = Path::new("day01/input/input.txt.sealed");
+        let sealed = crate::sealed::seal(&key, "\u{feff}1\r\n2\r\n".as_bytes());
+        assert_eq!(
+            decode_sealed(path, sealed.clone(), Some(&key)).unwrap(),
+            "1\n2\n"
+        );
+        assert!(matches!(
+            decode_sealed(path, sealed.clone(), None),
+            Err(InputError::NoKey { .. })
+        ));
+        assert!(matches!(
+            decode_sealed(path, sealed, Some(&Key::generate())),
+            Err(InputError::Unseal {
+                source: SealError::Decrypt,
+                ..
+            })
+        ));
+    }
+}
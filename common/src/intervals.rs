@@ -0,0 +1,278 @@
+//! Sets of integers stored as sorted, disjoint inclusive ranges
+//!
+//! Overlapping or adjacent ranges are merged on insertion, so `1..=3` and
+//! `4..=6` are kept as the single range `1..=6`. Ranges are inclusive so that
+//! sets can reach the maximum value of the integer type.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// Integer types an [`IntervalSet`] can hold
+pub trait Integer: Copy + Ord + Debug {
+    /// The next value, or `None` at the maximum
+    fn succ(self) -> Option<Self>;
+    /// The previous value, or `None` at the minimum
+    fn pred(self) -> Option<Self>;
+    /// Number of values in `start..=end`, saturating at `u128::MAX`
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end.abs_diff(start) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Whether a range ending at `end` overlaps or touches one starting at `start`
+fn touches<T: Integer>(end: T, start: T) -> bool {
+    start <= end || end.succ() == Some(start)
+}
+
+/// A set of integers as merged inclusive ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T: Integer> {
+    /// Start of each range mapped to its end
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            ranges: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the set holds no values
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of disjoint ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Number of values in the set, saturating at `u128::MAX`
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().fold(0u128, |total, (&start, &end)| {
+            total.saturating_add(T::span(start, end))
+        })
+    }
+
+    /// Add every value in `range`; empty ranges are ignored
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        if let Some((&s, &e)) = self.ranges.range(..=start).next_back()
+            && touches(e, start)
+        {
+            self.ranges.remove(&s);
+            start = s;
+            end = end.max(e);
+        }
+        while let Some((&s, &e)) = self.ranges.range(start..).next() {
+            if !touches(end, s) {
+                break;
+            }
+            self.ranges.remove(&s);
+            end = end.max(e);
+        }
+        self.ranges.insert(start, end);
+    }
+
+    /// Remove every value in `range`; empty ranges are ignored
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|&(_, &e)| e >= start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in overlapping {
+            self.ranges.remove(&s);
+            if s < start {
+                self.ranges.insert(s, start.pred().expect("start > s"));
+            }
+            if e > end {
+                self.ranges.insert(end.succ().expect("end < e"), e);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| end >= value)
+    }
+
+    /// The ranges in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// The ranges between consecutive ranges of the set, in ascending order
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .iter()
+            .zip(self.ranges.keys().skip(1))
+            .map(|((_, &end), &next)| {
+                // Ranges never touch, so the gap is never empty
+                end.succ().unwrap()..=next.pred().unwrap()
+            })
+    }
+
+    /// Values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = *x.start().max(y.start());
+            let end = *x.end().min(y.end());
+            if start <= end {
+                result.ranges.insert(start, end);
+            }
+            // Drop whichever range finishes first
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        result
+    }
+
+    /// Values in this set but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+
+    /// Values within `bounds` that are not in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut result = Self::new();
+        result.insert(bounds);
+        result.difference(self)
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent() {
+        let s = set(&[10..=14, 16..=20, 12..=18, 3..=5, 1..=2, 30..=30]);
+        assert_eq!(ranges(&s), vec![1..=5, 10..=20, 30..=30]);
+        assert_eq!(s.covered_len(), 5 + 11 + 1);
+        assert!(s.contains(15) && s.contains(30) && !s.contains(6) && !s.contains(31));
+    }
+
+    #[test]
+    fn test_insert_ignores_empty_ranges() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let s = set(&[5..=1]);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_remove_splits_ranges() {
+        let mut s = set(&[1..=10, 20..=30]);
+        s.remove(5..=22);
+        assert_eq!(ranges(&s), vec![1..=4, 23..=30]);
+        s.remove(23..=30);
+        assert_eq!(ranges(&s), vec![1..=4]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[1..=10, 20..=30]);
+        let b = set(&[5..=25]);
+        assert_eq!(ranges(&a.union(&b)), vec![1..=30]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=4, 26..=30]);
+        assert_eq!(ranges(&a.complement(0..=40)), vec![0..=0, 11..=19, 31..=40]);
+        assert_eq!(a.gaps().collect::<Vec<_>>(), vec![11..=19]);
+    }
+
+    #[test]
+    fn test_type_extremes() {
+        let mut s: IntervalSet<u8> = [0..=100, 101..=255].into_iter().collect();
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![0..=255]);
+        assert_eq!(s.covered_len(), 256);
+        s.remove(0..=0);
+        s.remove(255..=255);
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![1..=254]);
+        assert_eq!(
+            s.complement(0..=255).iter().collect::<Vec<_>>(),
+            vec![0..=0, 255..=255]
+        );
+    }
+}
@@ -1,58 +1,73 @@
 //! Common utilities for Advent of Code 2025
 
-use std::fmt::Display;
-use std::fs;
-use std::path::Path;
 use std::time::{Duration, Instant};
 
-/// Read input file for a given day
-pub fn read_input(day: u8) -> String {
-    let paths = [
-        format!("day{:02}/input/input.txt", day),
-        "input/input.txt".to_string(),
-    ];
-    for path in &paths {
-        if let Ok(content) = fs::read_to_string(path) {
-            return content;
-        }
-    }
-    panic!("Failed to read input file for day {:02}", day)
-}
-
-/// Read example input file for a given day
-pub fn read_example(day: u8) -> String {
-    let paths = [
-        format!("day{:02}/input/example.txt", day),
-        "input/example.txt".to_string(),
-    ];
-    for path in &paths {
-        if let Ok(content) = fs::read_to_string(path) {
-            return content;
-        }
-    }
-    panic!("Failed to read example file for day {:02}", day)
-}
-
-/// Read input from a specific path
-pub fn read_input_from_path<P: AsRef<Path>>(path: P) -> String {
-    fs::read_to_string(path.as_ref())
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", path.as_ref().display()))
-}
+mod answer;
+mod answers;
+pub mod bench;
+mod cli;
+pub mod dsu;
+mod examples;
+mod format;
+pub mod graph;
+mod grid;
+mod input;
+pub mod intervals;
+pub mod parse;
+pub mod sealed;
+mod testing;
+
+pub use answer::Answer;
+pub use answers::{ANSWERS_FILE, Expected, check_answer, input_hash, known_answers, parse_answers};
+pub use cli::{Args, Source, run_cli};
+pub use examples::{EXAMPLES_FILE, Example, check_examples, parse_examples, read_examples};
+pub use format::{CSV_HEADER, Format, git_revision};
+pub use grid::{Grid, Pos};
+pub use input::{
+    INPUT_ARG, INPUT_DIR_ENV, InputError, STDIN_PATH, WhitespaceIssue, check_whitespace,
+    normalize_input, read_example, read_input, read_input_from_path, try_read_example,
+    try_read_input, try_read_input_from_path, try_read_named_example, try_read_stdin,
+    workspace_root,
+};
+pub use parse::ParseError;
+pub use testing::{custom_test_input, skip_test, test_input};
 
 /// Result type for a day's solution
-pub struct DayResult<T: Display> {
-    pub part1: T,
-    pub part2: T,
+pub struct DayResult {
+    pub day: u8,
+    pub part1: Answer,
+    pub part2: Answer,
+    pub parse_time: Duration,
     pub part1_time: Duration,
     pub part2_time: Duration,
+    /// Set when only this part was run; the other is left `Unsolved`
+    pub only: Option<u8>,
 }
 
-impl<T: Display> DayResult<T> {
-    pub fn print(&self, day: u8) {
-        println!("=== Day {:02} ===", day);
-        println!("Part 1: {} ({:?})", self.part1, self.part1_time);
-        println!("Part 2: {} ({:?})", self.part2, self.part2_time);
-        println!("Total time: {:?}", self.part1_time + self.part2_time);
+impl DayResult {
+    /// Time spent parsing and solving both parts
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+
+    /// Number, answer and time of each part that was run
+    pub fn parts(&self) -> Vec<(u8, &Answer, Duration)> {
+        [
+            (1, &self.part1, self.part1_time),
+            (2, &self.part2, self.part2_time),
+        ]
+        .into_iter()
+        .filter(|&(part, _, _)| self.only.is_none_or(|only| only == part))
+        .collect()
+    }
+
+    pub fn print(&self) {
+        println!("=== Day {:02} ===", self.day);
+        println!("Parse time: {:?}", self.parse_time);
+        for (part, answer, time) in self.parts() {
+            println!("Part {}: {} ({:?})", part, answer, time);
+        }
+        println!("Total time: {:?}", self.total_time());
     }
 }
 
@@ -64,22 +79,62 @@ pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
     (result, elapsed)
 }
 
-/// Run both parts of a day's solution with timing
-pub fn run_day<T, F1, F2>(_day: u8, input: &str, part1: F1, part2: F2) -> DayResult<T>
-where
-    T: Display,
-    F1: FnOnce(&str) -> T,
-    F2: FnOnce(&str) -> T,
-{
-    let (p1, t1) = timed(|| part1(input));
-    let (p2, t2) = timed(|| part2(input));
+/// A day's puzzle solution
+///
+/// The input is parsed once and both parts work on the parsed value.
+pub trait Solution {
+    /// Day of the calendar this solves
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input
+    type Parsed;
 
-    DayResult {
+    /// Type of the part 1 answer
+    type Answer1: Into<Answer>;
+
+    /// Type of the part 2 answer
+    type Answer2: Into<Answer>;
+
+    /// Parse the raw puzzle input, reporting where it is malformed
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    /// Solve part 1
+    fn part1(parsed: &Self::Parsed) -> Self::Answer1;
+
+    /// Solve part 2
+    fn part2(parsed: &Self::Parsed) -> Self::Answer2;
+}
+
+/// Parse the input once, then run both parts on it, timing each phase
+pub fn run_day<S: Solution>(input: &str) -> Result<DayResult, ParseError> {
+    run_parts::<S>(input, None)
+}
+
+/// Like [`run_day`], but when `only` is 1 or 2 just that part is run
+pub fn run_parts<S: Solution>(input: &str, only: Option<u8>) -> Result<DayResult, ParseError> {
+    let runs = |part: u8| only.is_none_or(|only| only == part);
+    let (parsed, tp) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let (p1, t1) = if runs(1) {
+        timed(|| S::part1(&parsed).into())
+    } else {
+        (Answer::Unsolved, Duration::ZERO)
+    };
+    let (p2, t2) = if runs(2) {
+        timed(|| S::part2(&parsed).into())
+    } else {
+        (Answer::Unsolved, Duration::ZERO)
+    };
+
+    Ok(DayResult {
+        day: S::DAY,
         part1: p1,
         part2: p2,
+        parse_time: tp,
         part1_time: t1,
         part2_time: t2,
-    }
+        only,
+    })
 }
 
 /// Parse input lines into a vector
@@ -112,6 +167,56 @@ pub fn parse_digit_grid(input: &str) -> Vec<Vec<u32>> {
 mod tests {
     use super::*;
 
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Parsed = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            parse::Span::new(input)
+                .lines()
+                .map(|line| line.parse())
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Parsed) -> u32 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Self::Parsed) -> usize {
+            numbers.len()
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let result = run_day::<Sum>("1\n2\n3").unwrap();
+        assert_eq!(result.part1, Answer::from(6u32));
+        assert_eq!(result.part2, Answer::from(3usize));
+        assert_eq!(
+            result.total_time(),
+            result.parse_time + result.part1_time + result.part2_time
+        );
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        let result = run_parts::<Sum>("1\n2\n3", Some(2)).unwrap();
+        assert_eq!(result.part1, Answer::Unsolved);
+        assert_eq!(result.part2, Answer::from(3usize));
+        let parts: Vec<u8> = result.parts().iter().map(|&(part, _, _)| part).collect();
+        assert_eq!(parts, vec![2]);
+    }
+
+    #[test]
+    fn test_run_day_reports_bad_input() {
+        let err = run_day::<Sum>("1\nx\n3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
     #[test]
     fn test_parse_lines() {
         let input = "line1\nline2\nline3";
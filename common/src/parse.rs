@@ -0,0 +1,345 @@
+//! Parsing puzzle input with errors that point at the offending text
+//!
+//! A [`Span`] is a slice of the puzzle input that remembers where it came
+//! from. Splitting a span yields smaller spans, and any of them can turn into
+//! a [`ParseError`] carrying the line and column it starts at:
+//!
+//! ```
+//! use common::parse::Span;
+//!
+//! let input = Span::new("1,2,3\n4,x,6");
+//! let err = input.lines().map(|line| line.tuple::<i32, 3>()).nth(1).unwrap();
+//! assert_eq!(err.unwrap_err().to_string(), "line 2, column 3: expected i32, found 'x'");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+use std::str::FromStr;
+
+/// Malformed input, located by 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    /// Counted in characters, not bytes
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A piece of the puzzle input that knows its position in the whole
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole input
+    pub fn new(source: &'a str) -> Self {
+        Span {
+            source,
+            text: source,
+        }
+    }
+
+    /// A span for `text`, which must be a slice of this span's source
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        let start = self.source.as_ptr() as usize;
+        let at = text.as_ptr() as usize;
+        debug_assert!(at >= start && at + text.len() <= start + self.source.len());
+        Span {
+            source: self.source,
+            text,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Whether the span is empty or only whitespace
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Line and column where the span starts, both 1-based
+    pub fn location(&self) -> (usize, usize) {
+        let offset = self.text.as_ptr() as usize - self.source.as_ptr() as usize;
+        let before = &self.source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        (line, column)
+    }
+
+    /// An error located at the start of this span
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let (line, column) = self.location();
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// The part of the span covering the byte range
+    pub fn slice(&self, range: Range<usize>) -> Span<'a> {
+        self.sub(&self.text[range])
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let this = *self;
+        self.text.lines().map(move |line| this.sub(line))
+    }
+
+    /// Blocks of consecutive non-blank lines, as separated by blank lines
+    pub fn sections(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let this = *self;
+        let mut lines = self.text.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                last = line;
+            }
+            let start = first.as_ptr() as usize - this.text.as_ptr() as usize;
+            let end = last.as_ptr() as usize - this.text.as_ptr() as usize + last.len();
+            Some(this.sub(&this.text[start..end]))
+        })
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + 'a {
+        let this = *self;
+        self.text.split(separator).map(move |part| this.sub(part))
+    }
+
+    /// Pieces separated by whitespace
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let this = *self;
+        self.text.split_whitespace().map(move |word| this.sub(word))
+    }
+
+    /// The text before and after the first `separator`
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((before, after)) => Ok((self.sub(before), self.sub(after))),
+            None => Err(self.error(format!("expected '{}'", separator))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("expected '{}'", prefix))),
+        }
+    }
+
+    /// Parse the trimmed span as a `T`
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        trimmed.text.parse().map_err(|_| {
+            let expected = std::any::type_name::<T>();
+            if trimmed.is_empty() {
+                trimmed.error(format!("expected {}, found nothing", expected))
+            } else {
+                trimmed.error(format!("expected {}, found '{}'", expected, trimmed.text))
+            }
+        })
+    }
+
+    /// Integers separated by any run of the characters in `separators`
+    pub fn ints<T: FromStr>(&self, separators: &str) -> Result<Vec<T>, ParseError> {
+        let this = *self;
+        self.text
+            .split(|c| separators.contains(c))
+            .filter(|part| !part.is_empty())
+            .map(|part| this.sub(part).parse())
+            .collect()
+    }
+
+    /// An inclusive range written `a-b`; either bound may be negative
+    pub fn range<T: FromStr>(&self) -> Result<RangeInclusive<T>, ParseError> {
+        let trimmed = self.trim();
+        // Skip the first character so a leading minus sign is not the separator
+        let split = trimmed
+            .text
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i)
+            .ok_or_else(|| trimmed.error("expected a range 'a-b'"))?;
+        let start = trimmed.slice(0..split).parse()?;
+        let end = trimmed.slice(split + 1..trimmed.len()).parse()?;
+        Ok(start..=end)
+    }
+
+    /// Exactly `N` comma-separated values, such as `x,y,z`
+    pub fn tuple<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let parts: Vec<Span<'a>> = self.split(",").collect();
+        if parts.len() != N {
+            return Err(self.error(format!(
+                "expected {} comma-separated values, found {}",
+                N,
+                parts.len()
+            )));
+        }
+        let values = parts
+            .iter()
+            .map(Span::parse)
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(values
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length checked above")))
+    }
+
+    /// A bracketed group at the start of the span, after any whitespace
+    ///
+    /// `open` is one of `(`, `[`, `{` or `<`. Returns the text inside the
+    /// brackets and the text after the closing bracket. Nested groups of the
+    /// same kind are skipped over.
+    pub fn group(&self, open: char) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let close = closing(open);
+        let trimmed = self.sub(self.text.trim_start());
+        if !trimmed.text.starts_with(open) {
+            return Err(trimmed.error(format!("expected '{}'", open)));
+        }
+        let mut depth = 0;
+        for (i, c) in trimmed.text.char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+                if depth == 0 {
+                    let inside = trimmed.slice(open.len_utf8()..i);
+                    let rest = trimmed.slice(i + close.len_utf8()..trimmed.len());
+                    return Ok((inside, rest));
+                }
+            }
+        }
+        Err(trimmed.error(format!("unclosed '{}'", open)))
+    }
+
+    /// The insides of every top-level group opened by `open`, in order
+    pub fn groups(&self, open: char) -> Result<Vec<Span<'a>>, ParseError> {
+        let mut groups = Vec::new();
+        let mut rest = *self;
+        while let Some(start) = rest.text.find(open) {
+            let (inside, after) = rest.slice(start..rest.len()).group(open)?;
+            groups.push(inside);
+            rest = after;
+        }
+        Ok(groups)
+    }
+}
+
+fn closing(open: char) -> char {
+    match open {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => panic!("'{}' is not an opening bracket", open),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let input = Span::new("abc\nd€f\nghi");
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(line.location(), (2, 1));
+        let f = line.slice(4..5);
+        assert_eq!(f.as_str(), "f");
+        assert_eq!(f.error("bad").to_string(), "line 2, column 3: bad");
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = Span::new("a\nb\n\n\nc\n  \nd\ne\n");
+        let sections: Vec<&str> = input.sections().map(|s| s.as_str()).collect();
+        assert_eq!(sections, vec!["a\nb", "c", "d\ne"]);
+        assert_eq!(input.sections().nth(2).unwrap().location(), (7, 1));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input = Span::new("3, -4  5,6");
+        assert_eq!(input.ints::<i32>(", "), Ok(vec![3, -4, 5, 6]));
+        let err = input.ints::<u32>(", ").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(Span::new(" 17 ").parse::<u8>(), Ok(17));
+        assert!(Span::new("").parse::<u8>().is_err());
+    }
+
+    #[test]
+    fn test_range_and_tuple() {
+        assert_eq!(Span::new("11-22").range::<u64>(), Ok(11..=22));
+        assert_eq!(Span::new("-5--2").range::<i64>(), Ok(-5..=-2));
+        assert!(Span::new("11").range::<u64>().is_err());
+        assert_eq!(Span::new("1,2,3").tuple::<i64, 3>(), Ok([1, 2, 3]));
+        let err = Span::new("1,2").tuple::<i64, 3>().unwrap_err();
+        assert_eq!(err.message, "expected 3 comma-separated values, found 2");
+    }
+
+    #[test]
+    fn test_groups() {
+        let input = Span::new("[.##.] (3) (1,3) {3,5}");
+        let (lights, rest) = input.group('[').unwrap();
+        assert_eq!(lights.as_str(), ".##.");
+        let buttons: Vec<&str> = rest
+            .groups('(')
+            .unwrap()
+            .iter()
+            .map(|g| g.as_str())
+            .collect();
+        assert_eq!(buttons, vec!["3", "1,3"]);
+        assert_eq!(
+            Span::new("(a(b)c) d").group('(').unwrap().0.as_str(),
+            "a(b)c"
+        );
+
+        let err = Span::new("x (1,2").groups('(').unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (3, "unclosed '('"));
+        assert!(input.group('(').is_err());
+    }
+
+    #[test]
+    fn test_split_once_and_prefix() {
+        let line = Span::new("aaa: bbb ccc");
+        let (name, targets) = line.split_once(": ").unwrap();
+        assert_eq!(name.as_str(), "aaa");
+        assert_eq!(targets.words().count(), 2);
+        assert_eq!(
+            line.split_once(" -> ").unwrap_err().message,
+            "expected ' -> '"
+        );
+        assert_eq!(line.strip_prefix("aaa").unwrap().as_str(), ": bbb ccc");
+    }
+}
@@ -0,0 +1,216 @@
+//! Encrypted copies of puzzle inputs that can be committed
+//!
+//! Puzzle authors ask that inputs not be published, so `input.txt` is kept
+//! out of git and `input.txt.sealed` is committed instead: the input
+//! encrypted with XChaCha20-Poly1305 under a key that stays on your machines.
+//! The key is 64 hex digits in `$AOC_INPUT_KEY` or in `.aoc-key` at the
+//! workspace root, which git ignores.
+//!
+//! [`try_read_input`](crate::try_read_input) falls back to the sealed copy
+//! when there is no plain `input.txt`, so nothing else needs to know.
+
+use crate::input::workspace_root;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the key
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+
+/// File at the workspace root holding the key
+pub const KEY_FILE: &str = ".aoc-key";
+
+/// Appended to the name of the file a sealed copy was made from
+pub const SEALED_EXTENSION: &str = "sealed";
+
+/// Start of every sealed file, so a format change can be told apart
+const MAGIC: &[u8] = b"aoc-sealed-v1\n";
+
+const NONCE_LEN: usize = 24;
+
+/// Why a key could not be loaded or a file unsealed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SealError {
+    /// The key is not 64 hex digits
+    BadKey,
+    /// The file is not a sealed file
+    NotSealed,
+    /// The key is wrong or the file was changed
+    Decrypt,
+}
+
+impl fmt::Display for SealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SealError::BadKey => write!(
+                f,
+                "the key in ${} or {} is not 64 hex digits",
+                KEY_ENV, KEY_FILE
+            ),
+            SealError::NotSealed => write!(f, "not a sealed file"),
+            SealError::Decrypt => write!(f, "cannot decrypt: wrong key or damaged file"),
+        }
+    }
+}
+
+impl std::error::Error for SealError {}
+
+/// A 256-bit key for sealing inputs
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keep keys out of logs and test output
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    /// A new random key
+    pub fn generate() -> Key {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Key, SealError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(SealError::BadKey);
+        }
+        let mut key = [0; 32];
+        for (byte, pair) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| SealError::BadKey)?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| SealError::BadKey)?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+/// Where the key file is kept
+pub fn key_path() -> PathBuf {
+    workspace_root().join(KEY_FILE)
+}
+
+/// The key from `$AOC_INPUT_KEY` or `.aoc-key`, if either is set
+///
+/// An empty variable counts as unset, as CI leaves secrets that are not
+/// configured empty rather than undefined.
+pub fn load_key() -> Result<Option<Key>, SealError> {
+    let from_file = || fs::read_to_string(key_path()).ok();
+    match choose_key(std::env::var(KEY_ENV).ok(), from_file) {
+        Some(hex) => Key::from_hex(&hex).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// The first of the two sources that is set and not blank
+fn choose_key(
+    from_env: Option<String>,
+    from_file: impl FnOnce() -> Option<String>,
+) -> Option<String> {
+    let set = |hex: &String| !hex.trim().is_empty();
+    from_env.filter(set).or_else(|| from_file().filter(set))
+}
+
+/// The sealed copy of `path`: `input.txt` becomes `input.txt.sealed`
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(SEALED_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Encrypt `plaintext` with a fresh random nonce
+pub fn seal(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("encrypting in memory cannot fail");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+/// Decrypt the output of [`seal`]
+pub fn unseal(key: &Key, sealed: &[u8]) -> Result<Vec<u8>, SealError> {
+    let rest = sealed.strip_prefix(MAGIC).ok_or(SealError::NotSealed)?;
+    if rest.len() < NONCE_LEN {
+        return Err(SealError::NotSealed);
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| SealError::Decrypt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_round_trip() {
+        let key = Key::generate();
+        let sealed = seal(&key, b"1 2 3\n");
+        assert!(sealed.starts_with(MAGIC));
+        assert!(!sealed.windows(6).any(|w| w == b"1 2 3\n"));
+        assert_eq!(unseal(&key, &sealed), Ok(b"1 2 3\n".to_vec()));
+        // A fresh nonce every time
+        assert_ne!(seal(&key, b"1 2 3\n"), sealed);
+    }
+
+    #[test]
+    fn test_unseal_errors() {
+        let key = Key::generate();
+        let mut sealed = seal(&key, b"input");
+        assert_eq!(unseal(&Key::generate(), &sealed), Err(SealError::Decrypt));
+        *sealed.last_mut().unwrap() ^= 1;
+        assert_eq!(unseal(&key, &sealed), Err(SealError::Decrypt));
+        assert_eq!(unseal(&key, b"input"), Err(SealError::NotSealed));
+        assert_eq!(unseal(&key, MAGIC), Err(SealError::NotSealed));
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&format!("{}\n", key.to_hex())), Ok(key));
+        assert_eq!(Key::from_hex("abc"), Err(SealError::BadKey));
+        assert_eq!(Key::from_hex(&"zz".repeat(32)), Err(SealError::BadKey));
+        assert_eq!(format!("{:?}", Key::generate()), "Key(..)");
+    }
+
+    #[test]
+    fn test_blank_key_is_unset() {
+        let file = || Some("file".to_string());
+        assert_eq!(
+            choose_key(Some("env".to_string()), file),
+            Some("env".to_string())
+        );
+        assert_eq!(
+            choose_key(Some("  ".to_string()), file),
+            Some("file".to_string())
+        );
+        assert_eq!(choose_key(None, file), Some("file".to_string()));
+        assert_eq!(
+            choose_key(Some(String::new()), || Some("\n".to_string())),
+            None
+        );
+        assert_eq!(choose_key(None, || None), None);
+    }
+
+    #[test]
+    fn test_sealed_path() {
+        assert_eq!(
+            sealed_path(Path::new("day01/input/input.txt")),
+            Path::new("day01/input/input.txt.sealed")
+        );
+    }
+}
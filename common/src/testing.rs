@@ -0,0 +1,145 @@
+//! Generating the standard tests for a day crate
+//!
+//! Inside a day's test module, `common::aoc_tests!(Day05);` expands to
+//! `test_examples`, `test_part1` and `test_part2`, which check the examples
+//! manifest and the known answers for the puzzle input. Tests that call
+//! something other than `part1` or `part2` are listed in braces, each naming
+//! the example to parse (or `input` for the puzzle input) and the value the
+//! expression should produce:
+//!
+//! ```ignore
+//! common::aoc_tests!(Day08 {
+//!     // The example connects 10 pairs rather than 1000
+//!     test_part1_example: "example" |parsed| solve(parsed, 10) => 40,
+//! });
+//! ```
+//!
+//! Puzzle inputs are personal and may not be checked out, so every test on
+//! the puzzle input, custom or not, is skipped when it is missing, empty or
+//! sealed without a usable key.
+//! Example tests always run.
+
+use crate::input::{INPUT_DIR_ENV, InputError, try_read_input, try_read_named_example};
+use crate::sealed::SealError;
+use std::io::{self, Write};
+
+/// Say that a test was skipped
+///
+/// A skipped test still passes, and libtest hides what passing tests print
+/// with `eprintln!`, so this writes to stderr directly.
+pub fn skip_test(day: u8, test: &str, reason: &str) {
+    let _ = writeln!(io::stderr(), "skipped: day {:02} {}: {}", day, test, reason);
+}
+
+/// The puzzle input for a test, or `None` after reporting the test as
+/// skipped when the input is missing, empty or sealed without a usable key
+///
+/// Any other problem reading the input fails the test.
+pub fn test_input(day: u8, test: &str) -> Option<String> {
+    match try_read_input(day) {
+        Ok(input) => Some(input),
+        Err(InputError::NotFound { .. }) => {
+            let reason = format!(
+                "no puzzle input in day{:02}/input/input.txt or ${}",
+                day, INPUT_DIR_ENV
+            );
+            skip_test(day, test, &reason);
+            None
+        }
+        Err(
+            e @ (InputError::Empty { .. }
+            | InputError::NoKey { .. }
+            | InputError::Unseal {
+                source: SealError::BadKey | SealError::Decrypt,
+                ..
+            }),
+        ) => {
+            skip_test(day, test, &e.to_string());
+            None
+        }
+        Err(e) => panic!("Failed to read input file for day {:02}: {}", day, e),
+    }
+}
+
+/// Input for a custom test: the named example, or the puzzle input when
+/// `example` is `None`
+///
+/// Returns `None` if the test is skipped, as for [`test_input`]; a missing
+/// example is a failure.
+#[doc(hidden)]
+pub fn custom_test_input(day: u8, example: Option<&str>, test: &str) -> Option<String> {
+    match example {
+        Some(name) => Some(
+            try_read_named_example(day, name)
+                .unwrap_or_else(|e| panic!("Failed to read example '{}': {}", name, e)),
+        ),
+        None => test_input(day, test),
+    }
+}
+
+/// Standard tests for a day, plus any custom ones; see the module docs
+#[macro_export]
+macro_rules! aoc_tests {
+    (@input $day:ident, $name:ident, input) => {
+        $crate::custom_test_input(
+            <$day as $crate::Solution>::DAY,
+            None,
+            stringify!($name),
+        )
+    };
+    (@input $day:ident, $name:ident, $example:literal) => {
+        $crate::custom_test_input(
+            <$day as $crate::Solution>::DAY,
+            Some($example),
+            stringify!($name),
+        )
+    };
+    ($day:ident) => {
+        $crate::aoc_tests!($day {});
+    };
+    ($day:ident {
+        $(
+            $(#[$meta:meta])*
+            $name:ident : $source:tt |$parsed:ident| $body:expr => $expected:expr
+        ),* $(,)?
+    }) => {
+        #[test]
+        fn test_examples() {
+            $crate::check_examples::<$day>();
+        }
+
+        #[test]
+        fn test_part1() {
+            $crate::check_answer::<$day>(1);
+        }
+
+        #[test]
+        fn test_part2() {
+            $crate::check_answer::<$day>(2);
+        }
+
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let Some(input) = $crate::aoc_tests!(@input $day, $name, $source) else {
+                    return;
+                };
+                let parsed = <$day as $crate::Solution>::parse(&input)
+                    .unwrap_or_else(|e| panic!("bad input: {}", e));
+                let $parsed = &parsed;
+                assert_eq!($body, $expected);
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_input_is_skipped() {
+        assert_eq!(test_input(99, "test_part1"), None);
+    }
+}
@@ -0,0 +1,93 @@
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    /// Rotations as signed distances (L is negative, R is positive)
+    type Parsed = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Span::new(input)
+            .lines()
+            .filter(|line| !line.is_blank())
+            .map(|line| {
+                let line = line.trim();
+                if let Ok(distance) = line.strip_prefix("L") {
+                    Ok(-distance.parse::<i64>()?)
+                } else if let Ok(distance) = line.strip_prefix("R") {
+                    distance.parse()
+                } else {
+                    Err(line.error("expected a direction 'L' or 'R'"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(rotations: &Self::Parsed) -> i64 {
+        let mut position: i64 = 50;
+        let mut count = 0;
+
+        for &rotation in rotations {
+            position += rotation;
+
+            // Wrap around to 0-99 range
+            position = position.rem_euclid(100);
+
+            if position == 0 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn part2(rotations: &Self::Parsed) -> i64 {
+        let mut position: i64 = 50; // Raw position (not modulo), starts at 50
+        let mut count: i64 = 0;
+
+        for &rotation in rotations {
+            let new_position = position + rotation;
+
+            // Count multiples of 100 in range [low, high], excluding start position
+            let (low, high) = if position <= new_position {
+                (position, new_position)
+            } else {
+                (new_position, position)
+            };
+
+            // Count multiples of 100 in [low, high]
+            // = floor(high/100) - floor((low-1)/100)
+            let multiples_in_range = high.div_euclid(100) - (low - 1).div_euclid(100);
+
+            // Exclude start position if it's on a multiple of 100 (we don't count starting there)
+            let exclude_start = if position.rem_euclid(100) == 0 { 1 } else { 0 };
+
+            let crossings = (multiples_in_range - exclude_start).max(0);
+
+            count += crossings;
+            position = new_position; // Keep raw position for next iteration
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day01);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("L68\nX30").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Day01::parse("R1x").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 2));
+    }
+}
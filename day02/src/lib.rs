@@ -0,0 +1,156 @@
+use common::intervals::IntervalSet;
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day02;
+
+/// Check if a number is "invalid" for part 1 - repeated exactly twice.
+/// E.g., 11 (1 repeated), 6464 (64 repeated), 123123 (123 repeated)
+fn is_invalid_id_v1(n: u64) -> bool {
+    let s = n.to_string();
+    let len = s.len();
+
+    // Must have even length to be a repeated sequence
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let half = len / 2;
+    let (first_half, second_half) = s.split_at(half);
+
+    first_half == second_half
+}
+
+/// Check if a number is "invalid" for part 2 - repeated at least twice.
+/// E.g., 111 (1 repeated 3x), 1212 (12 repeated 2x), 123123123 (123 repeated 3x)
+fn is_invalid_id_v2(n: u64) -> bool {
+    let s = n.to_string();
+    let len = s.len();
+
+    // Try all possible pattern lengths from 1 to len/2
+    for pattern_len in 1..=len / 2 {
+        // Pattern must divide evenly into the total length
+        if !len.is_multiple_of(pattern_len) {
+            continue;
+        }
+
+        let pattern = &s[..pattern_len];
+        let repetitions = len / pattern_len;
+
+        // Need at least 2 repetitions
+        if repetitions < 2 {
+            continue;
+        }
+
+        // Check if the entire string is this pattern repeated
+        let mut matches = true;
+        for i in 1..repetitions {
+            let start = i * pattern_len;
+            let end = start + pattern_len;
+            if &s[start..end] != pattern {
+                matches = false;
+                break;
+            }
+        }
+
+        if matches {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Parse comma-separated ranges, which may wrap across lines
+///
+/// Ranges that overlap are merged, so an ID in several ranges counts once.
+fn parse_ranges(input: &str) -> Result<IntervalSet<u64>, ParseError> {
+    let mut ranges = IntervalSet::new();
+
+    for range in Span::new(input).split(",") {
+        let range = range.trim();
+        if range.is_empty() {
+            continue;
+        }
+        if range.as_str().contains('\n') {
+            // A line break inside a range only wraps it
+            let joined = range.as_str().replace('\n', "");
+            let parsed = Span::new(&joined)
+                .range()
+                .map_err(|e| range.error(e.message))?;
+            ranges.insert(parsed);
+        } else {
+            ranges.insert(range.range()?);
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Find all invalid IDs using the given validation function
+fn find_invalid_ids<F>(ranges: &IntervalSet<u64>, is_invalid: F) -> Vec<u64>
+where
+    F: Fn(u64) -> bool,
+{
+    let mut invalid_ids = Vec::new();
+
+    for range in ranges.iter() {
+        for n in range {
+            if is_invalid(n) {
+                invalid_ids.push(n);
+            }
+        }
+    }
+
+    invalid_ids
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// Inclusive ID ranges
+    type Parsed = IntervalSet<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Parsed) -> u64 {
+        let invalid_ids = find_invalid_ids(ranges, is_invalid_id_v1);
+        invalid_ids.iter().sum()
+    }
+
+    fn part2(ranges: &Self::Parsed) -> u64 {
+        let invalid_ids = find_invalid_ids(ranges, is_invalid_id_v2);
+        invalid_ids.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day02);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("11-22,95+115").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = Day02::parse("11-22,\n95-1\nx5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_range_wraps_across_lines() {
+        let ranges = Day02::parse("11-2\n2,95-\n115\n").unwrap();
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![11..=22, 95..=115]);
+    }
+
+    #[test]
+    fn test_overlapping_ranges_count_once() {
+        let ranges = Day02::parse("11-22,20-33").unwrap();
+        assert_eq!(Day02::part1(&ranges), 11 + 22 + 33);
+    }
+}
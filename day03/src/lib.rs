@@ -0,0 +1,123 @@
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day03;
+
+/// Batteries turned on in each bank for part 2
+const PART2_BATTERIES: usize = 12;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    /// Battery joltage digits, one bank per line
+    type Parsed = Vec<Vec<u32>>;
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Span::new(input)
+            .lines()
+            .filter(|line| !line.is_blank())
+            .map(|line| {
+                let line = line.trim();
+                let digits = line
+                    .as_str()
+                    .char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10).ok_or_else(|| {
+                            line.slice(i..i + c.len_utf8())
+                                .error(format!("expected a digit, found '{}'", c))
+                        })
+                    })
+                    .collect::<Result<Vec<u32>, ParseError>>()?;
+                if digits.len() < PART2_BATTERIES {
+                    return Err(line.error(format!(
+                        "expected at least {} batteries, found {}",
+                        PART2_BATTERIES,
+                        digits.len()
+                    )));
+                }
+                Ok(digits)
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Parsed) -> i64 {
+        // For each bank, find the maximum 2-digit joltage by picking exactly 2 batteries
+        // The two digits must maintain their relative order (first picked is tens, second is ones)
+        banks
+            .iter()
+            .map(|digits| {
+                let mut max_joltage = 0i64;
+
+                // Try all pairs (i, j) where i < j
+                for i in 0..digits.len() {
+                    for j in (i + 1)..digits.len() {
+                        let joltage = (digits[i] * 10 + digits[j]) as i64;
+                        max_joltage = max_joltage.max(joltage);
+                    }
+                }
+                max_joltage
+            })
+            .sum()
+    }
+
+    fn part2(banks: &Self::Parsed) -> u64 {
+        // Select exactly 12 batteries to form the maximum 12-digit number
+        // Greedy approach: at each position, pick the largest digit possible
+        // while ensuring enough digits remain for the rest
+        banks
+            .iter()
+            .map(|digits| {
+                let n = digits.len();
+                let k = PART2_BATTERIES;
+
+                let mut result: u64 = 0;
+                let mut start = 0; // current starting position to search from
+
+                for i in 0..k {
+                    // Need to pick (k - i) more digits including this one
+                    // So we can search up to index n - (k - i)
+                    let remaining_needed = k - i;
+                    let end = n - remaining_needed; // inclusive end position we can pick from
+
+                    // Find the maximum digit in range [start, end]
+                    let mut max_digit = 0;
+                    let mut max_pos = start;
+                    for (pos, &digit) in digits.iter().enumerate().take(end + 1).skip(start) {
+                        let digit = digit as u64;
+                        if digit > max_digit {
+                            max_digit = digit;
+                            max_pos = pos;
+                        }
+                    }
+
+                    result = result * 10 + max_digit;
+                    start = max_pos + 1; // next search starts after the picked position
+                }
+
+                result
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day03);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day03::parse("987654321111111\n81111a1111111119")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = Day03::parse("987654321111111\n12345").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 1, "expected at least 12 batteries, found 5")
+        );
+    }
+}
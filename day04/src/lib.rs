@@ -0,0 +1,88 @@
+use common::parse::Span;
+use common::{Grid, ParseError, Pos, Solution};
+
+pub struct Day04;
+
+fn count_adjacent(grid: &Grid<char>, pos: Pos) -> usize {
+    grid.neighbors8(pos).filter(|&n| grid[n] == '@').count()
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    /// Grid of paper rolls
+    type Parsed = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut width = None;
+        for line in Span::new(input).lines() {
+            let text = line.as_str();
+            if let Some((i, c)) = text.char_indices().find(|&(_, c)| c != '@' && c != '.') {
+                let cell = line.slice(i..i + c.len_utf8());
+                return Err(cell.error(format!("expected '@' or '.', found '{}'", c)));
+            }
+            match width {
+                None => width = Some(text.len()),
+                Some(width) if width != text.len() => {
+                    return Err(line.error(format!(
+                        "expected a row of {} cells, found {}",
+                        width,
+                        text.len()
+                    )));
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Grid::parse(input))
+    }
+
+    fn part1(grid: &Self::Parsed) -> usize {
+        // Accessible if fewer than 4 adjacent rolls (8 directions)
+        grid.iter()
+            .filter(|&(pos, &cell)| cell == '@' && count_adjacent(grid, pos) < 4)
+            .count()
+    }
+
+    fn part2(grid: &Self::Parsed) -> usize {
+        let mut grid = grid.clone();
+        let mut total_removed = 0;
+
+        loop {
+            // Find all accessible rolls (fewer than 4 adjacent)
+            let to_remove: Vec<Pos> = grid
+                .positions()
+                .filter(|&pos| grid[pos] == '@' && count_adjacent(&grid, pos) < 4)
+                .collect();
+
+            if to_remove.is_empty() {
+                break;
+            }
+
+            // Remove all accessible rolls
+            for &pos in &to_remove {
+                grid[pos] = '.';
+            }
+
+            total_removed += to_remove.len();
+        }
+
+        total_removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day04);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day04::parse("..@\n.x@\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Day04::parse("..@\n.@\n").err().unwrap();
+        assert_eq!(err.message, "expected a row of 3 cells, found 2");
+    }
+}
@@ -0,0 +1,66 @@
+use common::intervals::IntervalSet;
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day05;
+
+fn parse_input(input: &str) -> Result<(IntervalSet<i64>, Vec<i64>), ParseError> {
+    let input = Span::new(input);
+    let mut sections = input.sections();
+    let ranges = sections
+        .next()
+        .ok_or_else(|| input.error("expected fresh ID ranges"))?;
+    let ids = sections
+        .next()
+        .ok_or_else(|| input.error("expected ingredient IDs after a blank line"))?;
+
+    // Overlapping and adjacent ranges are merged as they are inserted
+    let fresh = ranges
+        .lines()
+        .map(|line| line.range())
+        .collect::<Result<IntervalSet<i64>, _>>()?;
+
+    let ingredients = ids
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    Ok((fresh, ingredients))
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    /// Fresh ID ranges and available ingredient IDs
+    type Parsed = (IntervalSet<i64>, Vec<i64>);
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(database: &Self::Parsed) -> usize {
+        let (fresh, ingredients) = database;
+        ingredients.iter().filter(|&&id| fresh.contains(id)).count()
+    }
+
+    fn part2(database: &Self::Parsed) -> u128 {
+        let (fresh, _) = database;
+        fresh.covered_len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day05);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day05::parse("3-5\n10-14\n\n1\nfive").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert!(Day05::parse("3-5\n10-14\n").is_err());
+    }
+}
@@ -0,0 +1,206 @@
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day06;
+
+/// A problem spans the columns `start..end` of the worksheet
+struct Problem {
+    start: usize,
+    end: usize,
+    operator: char,
+}
+
+/// Worksheet rows padded to equal width, split into problems
+pub struct Worksheet {
+    number_lines: Vec<Vec<char>>,
+    problems: Vec<Problem>,
+}
+
+fn parse_worksheet(input: &str) -> Result<Worksheet, ParseError> {
+    let mut lines: Vec<Span> = Span::new(input).lines().collect();
+    while lines.last().is_some_and(Span::is_blank) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        return Ok(Worksheet {
+            number_lines: Vec::new(),
+            problems: Vec::new(),
+        });
+    }
+
+    // Number rows hold only digits and spaces
+    for line in &lines[..lines.len() - 1] {
+        let text = line.as_str();
+        if let Some((i, c)) = text
+            .char_indices()
+            .find(|&(_, c)| c != ' ' && !c.is_ascii_digit())
+        {
+            let at = line.slice(i..i + c.len_utf8());
+            return Err(at.error(format!("expected a digit, found '{}'", c)));
+        }
+    }
+    let operator_span = lines[lines.len() - 1];
+
+    // Find the maximum line length to handle ragged lines
+    let max_len = lines
+        .iter()
+        .map(|l| l.as_str().chars().count())
+        .max()
+        .unwrap_or(0);
+
+    // Pad all lines to the same length
+    let padded_lines: Vec<Vec<char>> = lines
+        .iter()
+        .map(|l| {
+            format!("{:width$}", l.as_str(), width = max_len)
+                .chars()
+                .collect()
+        })
+        .collect();
+
+    // The last line contains the operators
+    let (operator_line, number_lines) = padded_lines.split_last().unwrap();
+
+    // Find problem boundaries by looking for columns that are all spaces in number lines
+    // Problems are separated by full columns of spaces
+    let is_separator = |col: usize| number_lines.iter().all(|l| l[col] == ' ');
+
+    let mut problems = Vec::new();
+    let mut col = 0;
+
+    while col < max_len {
+        // Skip separator columns (all spaces in number lines)
+        while col < max_len && is_separator(col) {
+            col += 1;
+        }
+
+        if col >= max_len {
+            break;
+        }
+
+        // Find the end of this problem (next all-space column or end)
+        let start = col;
+        while col < max_len && !is_separator(col) {
+            col += 1;
+        }
+        let end = col;
+
+        // Find the operator for this problem (should be in the operator line within this column range)
+        let operator = operator_line[start..end]
+            .iter()
+            .copied()
+            .find(|&c| c == '+' || c == '*')
+            .ok_or_else(|| {
+                // Point at the problem's first column in the operator row
+                let text = operator_span.as_str();
+                let offset = text
+                    .char_indices()
+                    .nth(start)
+                    .map_or(text.len(), |(i, _)| i);
+                operator_span
+                    .slice(offset..text.len())
+                    .error("expected an operator '+' or '*'")
+            })?;
+
+        problems.push(Problem {
+            start,
+            end,
+            operator,
+        });
+    }
+
+    Ok(Worksheet {
+        number_lines: number_lines.to_vec(),
+        problems,
+    })
+}
+
+/// Apply each problem's operator to its numbers and add up the results
+fn grand_total<F>(worksheet: &Worksheet, read_numbers: F) -> i64
+where
+    F: Fn(&Problem) -> Vec<i64>,
+{
+    let mut grand_total: i64 = 0;
+    for problem in &worksheet.problems {
+        let numbers = read_numbers(problem);
+        if numbers.is_empty() {
+            continue;
+        }
+        let result = match problem.operator {
+            '+' => numbers.iter().sum(),
+            '*' => numbers.iter().product(),
+            _ => 0,
+        };
+        grand_total += result;
+    }
+
+    grand_total
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Worksheet;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_worksheet(input)
+    }
+
+    fn part1(worksheet: &Self::Parsed) -> i64 {
+        // Each row of a problem holds one number
+        grand_total(worksheet, |problem| {
+            let mut numbers: Vec<i64> = Vec::new();
+            for line in &worksheet.number_lines {
+                let segment: String = line[problem.start..problem.end].iter().collect();
+                let trimmed = segment.trim();
+                if !trimmed.is_empty()
+                    && let Ok(n) = trimmed.parse::<i64>()
+                {
+                    numbers.push(n);
+                }
+            }
+            numbers
+        })
+    }
+
+    fn part2(worksheet: &Self::Parsed) -> i64 {
+        // In cephalopod math, each column is a digit of a number
+        // Most significant digit at top, least significant at bottom
+        // Read columns from right to left within the problem
+        grand_total(worksheet, |problem| {
+            let mut numbers: Vec<i64> = Vec::new();
+            for c in (problem.start..problem.end).rev() {
+                // Build the number from this column (top to bottom = most to least significant)
+                let mut num: i64 = 0;
+                let mut has_digit = false;
+                for line in &worksheet.number_lines {
+                    if let Some(digit) = line[c].to_digit(10) {
+                        num = num * 10 + digit as i64;
+                        has_digit = true;
+                    }
+                }
+                if has_digit {
+                    numbers.push(num);
+                }
+            }
+            numbers
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day06);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day06::parse("123 328\n 45 64x\n*   +  ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+        let err = Day06::parse("123 328\n 45 64 \n*      ").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 5));
+    }
+}
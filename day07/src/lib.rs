@@ -0,0 +1,126 @@
+use common::parse::Span;
+use common::{Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day07;
+
+/// Find the column of the starting position S, which parsing checked for
+fn find_start(grid: &Grid<char>) -> usize {
+    grid.find(&'S').map(|(_, col)| col).unwrap()
+}
+
+/// Whether a beam in this column leaves the manifold
+///
+/// Ragged lines are padded with spaces, which count as off the edge just like
+/// columns past the right of the grid.
+fn exits(row: &[char], col: usize) -> bool {
+    row.get(col).is_none_or(|&ch| ch == ' ')
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// Manifold diagram, lines may be ragged
+    type Parsed = Grid<char>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let grid = Grid::parse(input);
+        if grid.find(&'S').is_none() {
+            return Err(Span::new(input).error("no starting position 'S'"));
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Parsed) -> i64 {
+        let start_col = find_start(grid);
+
+        // Track active beam columns at each row level
+        // Beams at same position merge into one
+        let mut beams: HashSet<usize> = HashSet::new();
+        beams.insert(start_col);
+
+        let mut splits = 0;
+
+        // Process each row starting from row 1 (after S)
+        for row in grid.rows().skip(1) {
+            let mut new_beams: HashSet<usize> = HashSet::new();
+
+            for &col in &beams {
+                // Handle ragged lines - if column is beyond this row, beam exits
+                if exits(row, col) {
+                    continue;
+                }
+                let ch = row[col];
+                if ch == '^' {
+                    // Split: beam stops, creates left and right beams
+                    splits += 1;
+                    if col > 0 {
+                        new_beams.insert(col - 1);
+                    }
+                    new_beams.insert(col + 1);
+                } else {
+                    // Continue downward
+                    new_beams.insert(col);
+                }
+            }
+
+            beams = new_beams;
+        }
+
+        splits
+    }
+
+    fn part2(grid: &Self::Parsed) -> i64 {
+        use std::collections::HashMap;
+
+        let start_col = find_start(grid);
+
+        // Track number of timelines at each column position
+        // When a particle hits a splitter, each timeline splits into 2
+        let mut timelines: HashMap<usize, i64> = HashMap::new();
+        timelines.insert(start_col, 1);
+
+        // Process each row starting from row 1 (after S)
+        for row in grid.rows().skip(1) {
+            let mut new_timelines: HashMap<usize, i64> = HashMap::new();
+
+            for (&col, &count) in &timelines {
+                // Handle ragged lines - if column is beyond this row, timelines exit
+                if exits(row, col) {
+                    continue;
+                }
+                let ch = row[col];
+                if ch == '^' {
+                    // Split: each timeline becomes 2 (one left, one right)
+                    if col > 0 {
+                        *new_timelines.entry(col - 1).or_insert(0) += count;
+                    }
+                    *new_timelines.entry(col + 1).or_insert(0) += count;
+                } else {
+                    // Continue downward
+                    *new_timelines.entry(col).or_insert(0) += count;
+                }
+            }
+
+            timelines = new_timelines;
+        }
+
+        // Sum all timelines
+        timelines.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day07);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day07::parse("..^..\n.....").err().unwrap();
+        assert_eq!(err.message, "no starting position 'S'");
+    }
+}
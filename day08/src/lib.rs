@@ -0,0 +1,121 @@
+use common::dsu::Dsu;
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day08;
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point {
+    fn distance_squared(&self, other: &Point) -> i64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    Span::new(input)
+        .lines()
+        .filter(|line| !line.is_blank())
+        .map(|line| {
+            let [x, y, z] = line.tuple()?;
+            Ok(Point { x, y, z })
+        })
+        .collect()
+}
+
+/// Junction boxes and every pair of them, closest first
+pub struct Playground {
+    points: Vec<Point>,
+    pairs: Vec<(i64, usize, usize)>,
+}
+
+fn sorted_pairs(points: &[Point]) -> Vec<(i64, usize, usize)> {
+    let n = points.len();
+
+    // Generate all pairs with their distances
+    let mut pairs: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = points[i].distance_squared(&points[j]);
+            pairs.push((dist, i, j));
+        }
+    }
+
+    // Sort pairs by distance
+    pairs.sort_by_key(|&(dist, _, _)| dist);
+    pairs
+}
+
+fn solve(playground: &Playground, num_connections: usize) -> usize {
+    // Use Union-Find to connect pairs
+    let mut dsu = Dsu::new(playground.points.len());
+
+    for &(_, i, j) in playground.pairs.iter().take(num_connections) {
+        // Try to connect (even if already connected, count it)
+        dsu.union(i, j);
+    }
+
+    // Get circuit sizes and multiply top 3
+    let mut sizes = dsu.component_sizes();
+    sizes.sort_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Playground;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let points = parse_input(input)?;
+        let pairs = sorted_pairs(&points);
+        Ok(Playground { points, pairs })
+    }
+
+    fn part1(playground: &Self::Parsed) -> usize {
+        solve(playground, 1000)
+    }
+
+    fn part2(playground: &Self::Parsed) -> i64 {
+        let points = &playground.points;
+
+        // Use Union-Find to connect pairs until all in one circuit
+        let mut dsu = Dsu::new(points.len());
+
+        for &(_, i, j) in &playground.pairs {
+            if dsu.union(i, j) && dsu.components() == 1 {
+                // This was the last connection needed
+                return points[i].x * points[j].x;
+            }
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day08 {
+        // After 10 connections: 5 * 4 * 2 = 40
+        test_part1_example: "example" |parsed| solve(parsed, 10) => 40,
+    });
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day08::parse("162,817,812\n57,618").err().unwrap();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.message, "expected 3 comma-separated values, found 2");
+    }
+}
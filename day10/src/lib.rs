@@ -0,0 +1,556 @@
+use common::parse::Span;
+use common::{Answer, ParseError, Solution};
+
+pub struct Day10;
+
+/// Lights and buttons are bitmasks in a `u64`
+const MAX_LIGHTS: usize = 64;
+const MAX_BUTTONS: usize = 64;
+
+/// A machine as (target_lights, buttons, joltage)
+type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<u64>);
+
+/// Parse a machine definition line into (target_lights, buttons, joltage)
+/// target_lights: Vec<bool> where true = light should be ON
+/// buttons: Vec<Vec<usize>> where each inner vec is the indices affected by that button
+/// joltage: Vec<u64> target joltage values for each counter
+fn parse_machine(line: Span) -> Result<Machine, ParseError> {
+    // Parse indicator lights [.##.]
+    let (lights, rest) = line.group('[')?;
+    let target_lights = lights
+        .as_str()
+        .char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(lights
+                .slice(i..i + c.len_utf8())
+                .error(format!("expected '.' or '#', found '{}'", c))),
+        })
+        .collect::<Result<Vec<bool>, _>>()?;
+    if target_lights.len() > MAX_LIGHTS {
+        return Err(lights.error(format!(
+            "expected at most {} lights, found {}",
+            MAX_LIGHTS,
+            target_lights.len()
+        )));
+    }
+
+    // Buttons (1,3) come before the joltage requirements {3,5,4,7}
+    let curly = rest
+        .as_str()
+        .find('{')
+        .ok_or_else(|| rest.error("expected joltage requirements '{...}'"))?;
+    let buttons_section = rest.slice(0..curly);
+    let (joltage_section, _) = rest.slice(curly..rest.len()).group('{')?;
+
+    // Parse buttons (indices) - every parenthesized group
+    let mut buttons = Vec::new();
+    for group in buttons_section.groups('(')? {
+        let indices: Vec<usize> = group.ints(", ")?;
+        if indices.iter().any(|&i| i >= target_lights.len()) {
+            return Err(group.error(format!(
+                "button wired to a light beyond the {} lights",
+                target_lights.len()
+            )));
+        }
+        buttons.push(indices);
+    }
+    if buttons.len() > MAX_BUTTONS {
+        return Err(buttons_section.error(format!(
+            "expected at most {} buttons, found {}",
+            MAX_BUTTONS,
+            buttons.len()
+        )));
+    }
+    if !in_span(mask(&target_lights), &button_masks(&buttons)) {
+        return Err(lights.error("no combination of buttons gives this light pattern"));
+    }
+
+    // Parse joltage requirements, one per light
+    let joltage: Vec<u64> = joltage_section.ints(", ")?;
+    if joltage.len() != target_lights.len() {
+        return Err(joltage_section.error(format!(
+            "expected {} joltage requirements, found {}",
+            target_lights.len(),
+            joltage.len()
+        )));
+    }
+    let wired = button_masks(&buttons).into_iter().fold(0, |all, b| all | b);
+    if let Some(light) = (0..joltage.len()).find(|&i| joltage[i] > 0 && wired & (1 << i) == 0) {
+        return Err(joltage_section.error(format!(
+            "joltage required on light {}, which no button is wired to",
+            light
+        )));
+    }
+
+    Ok((target_lights, buttons, joltage))
+}
+
+/// Lights that are on as a bitmask
+fn mask(lights: &[bool]) -> u64 {
+    lights
+        .iter()
+        .enumerate()
+        .filter(|&(_, &on)| on)
+        .fold(0, |mask, (i, _)| mask | 1u64 << i)
+}
+
+/// Each button as a bitmask of the lights it toggles
+fn button_masks(buttons: &[Vec<usize>]) -> Vec<u64> {
+    buttons
+        .iter()
+        .map(|indices| indices.iter().fold(0, |mask, &i| mask | 1u64 << i))
+        .collect()
+}
+
+/// Whether some subset of `masks` XORs to `target`
+fn in_span(target: u64, masks: &[u64]) -> bool {
+    // Reduce against a basis kept with distinct leading bits
+    let mut basis: Vec<u64> = Vec::new();
+    let reduce = |basis: &[u64], mut value: u64| {
+        for &b in basis {
+            value = value.min(value ^ b);
+        }
+        value
+    };
+    for &m in masks {
+        let m = reduce(&basis, m);
+        if m != 0 {
+            basis.push(m);
+            basis.sort_unstable_by(|a, b| b.cmp(a));
+        }
+    }
+    reduce(&basis, target) == 0
+}
+
+/// Find minimum number of button presses to achieve target light configuration
+/// This is a subset-sum problem in GF(2), looking for minimum weight solution
+fn min_presses(target: &[bool], buttons: &[Vec<usize>]) -> Option<u64> {
+    let n_buttons = buttons.len();
+    let target_mask = mask(target);
+    let button_masks = button_masks(buttons);
+
+    // Brute force: try all 2^n_buttons combinations, find one matching target with minimum popcount
+    // This works for small n_buttons (up to ~20 or so)
+    if n_buttons <= 25 {
+        let mut min_presses = u64::MAX;
+
+        for combo in 0u64..(1u64 << n_buttons) {
+            let mut result: u64 = 0;
+            for (i, &mask) in button_masks.iter().enumerate() {
+                if combo & (1u64 << i) != 0 {
+                    result ^= mask;
+                }
+            }
+            if result == target_mask {
+                let presses = combo.count_ones() as u64;
+                min_presses = min_presses.min(presses);
+            }
+        }
+
+        (min_presses != u64::MAX).then_some(min_presses)
+    } else {
+        // For larger inputs, use meet-in-the-middle
+        meet_in_the_middle(target_mask, &button_masks)
+    }
+}
+
+/// Meet-in-the-middle approach for larger button counts
+fn meet_in_the_middle(target: u64, button_masks: &[u64]) -> Option<u64> {
+    use std::collections::HashMap;
+
+    let n = button_masks.len();
+    let half = n / 2;
+
+    // First half: map XOR result -> minimum presses to achieve it
+    let mut first_half: HashMap<u64, u32> = HashMap::new();
+    for combo in 0u64..(1u64 << half) {
+        let mut result: u64 = 0;
+        for (i, &mask) in button_masks.iter().enumerate().take(half) {
+            if combo & (1u64 << i) != 0 {
+                result ^= mask;
+            }
+        }
+        let presses = combo.count_ones();
+        first_half
+            .entry(result)
+            .and_modify(|e| *e = (*e).min(presses))
+            .or_insert(presses);
+    }
+
+    // Second half: for each combination, check what first-half result we need
+    let mut min_presses = u64::MAX;
+    let second_half_size = n - half;
+    for combo in 0u64..(1u64 << second_half_size) {
+        let mut result: u64 = 0;
+        for i in 0..second_half_size {
+            if combo & (1u64 << i) != 0 {
+                result ^= button_masks[half + i];
+            }
+        }
+        // We need first_half XOR result = target, so first_half = target XOR result
+        let needed = target ^ result;
+        if let Some(&first_presses) = first_half.get(&needed) {
+            let total = first_presses as u64 + combo.count_ones() as u64;
+            min_presses = min_presses.min(total);
+        }
+    }
+
+    (min_presses != u64::MAX).then_some(min_presses)
+}
+
+/// Find minimum button presses to reach target joltage values
+/// Each button increments certain counters by 1
+/// This is an Integer Linear Programming problem: minimize sum(x_i) subject to A*x = b, x >= 0
+fn min_presses_joltage(target: &[u64], buttons: &[Vec<usize>]) -> u64 {
+    let n_counters = target.len();
+    let n_buttons = buttons.len();
+
+    if n_buttons == 0 {
+        return if target.iter().all(|&t| t == 0) {
+            0
+        } else {
+            u64::MAX
+        };
+    }
+
+    // Build matrix A where A[i][j] = 1 if button j affects counter i
+    let mut matrix: Vec<Vec<i64>> = vec![vec![0; n_buttons]; n_counters];
+    for (j, button) in buttons.iter().enumerate() {
+        for &counter_idx in button {
+            if counter_idx < n_counters {
+                matrix[counter_idx][j] = 1;
+            }
+        }
+    }
+
+    let target_i64: Vec<i64> = target.iter().map(|&t| t as i64).collect();
+
+    // Use Gaussian elimination to solve the system, then search for minimum sum solution
+    solve_min_sum_ilp(&matrix, &target_i64, n_buttons, n_counters)
+}
+
+/// Solve Ax = b for non-negative integers x, minimizing sum(x)
+/// Uses Gaussian elimination to find particular solution + null space, then searches
+fn solve_min_sum_ilp(
+    matrix: &[Vec<i64>],
+    target: &[i64],
+    n_buttons: usize,
+    n_counters: usize,
+) -> u64 {
+    // Augmented matrix [A | b]
+    let mut aug: Vec<Vec<i64>> = matrix
+        .iter()
+        .zip(target)
+        .map(|(row, &t)| {
+            let mut r = row.clone();
+            r.push(t);
+            r
+        })
+        .collect();
+
+    let n_cols = n_buttons;
+
+    // Gaussian elimination with partial pivoting (over rationals, but we track denominators)
+    // Actually, let's use integer elimination with LCM to avoid fractions
+
+    // Track which columns are pivot columns
+    let mut pivot_cols: Vec<usize> = Vec::new();
+    let mut pivot_row = 0;
+
+    for col in 0..n_cols {
+        if pivot_row >= n_counters {
+            break;
+        }
+
+        // Find pivot
+        let pivot_idx = (pivot_row..n_counters).find(|&row| aug[row][col] != 0);
+
+        let Some(p_idx) = pivot_idx else {
+            continue;
+        };
+
+        // Swap rows
+        aug.swap(pivot_row, p_idx);
+        pivot_cols.push(col);
+
+        // Eliminate other rows
+        let pivot = aug[pivot_row].clone();
+        let pivot_val = pivot[col];
+        for (row, aug_row) in aug.iter_mut().enumerate() {
+            if row != pivot_row && aug_row[col] != 0 {
+                let factor = aug_row[col];
+                for (cell, &p) in aug_row.iter_mut().zip(&pivot) {
+                    *cell = *cell * pivot_val - factor * p;
+                }
+            }
+        }
+
+        pivot_row += 1;
+    }
+
+    // Check for inconsistent system
+    for aug_row in aug.iter().take(n_counters).skip(pivot_row) {
+        if aug_row[n_cols] != 0 {
+            return u64::MAX; // No solution
+        }
+    }
+
+    // Free variables are those not in pivot_cols
+    let free_vars: Vec<usize> = (0..n_buttons).filter(|c| !pivot_cols.contains(c)).collect();
+
+    let n_free = free_vars.len();
+
+    // For each assignment of free variables, compute pivot variables
+    // Then check if all are non-negative integers
+
+    // The pivot variables are determined by:
+    // x_pivot[i] = (aug[i][n_cols] - sum(aug[i][free_j] * x_free[j])) / aug[i][pivot_cols[i]]
+
+    // We need to search over non-negative integer values for free variables
+    // The range is bounded by: x_free[j] <= max_target / contribution
+
+    let max_target = target.iter().copied().max().unwrap_or(0) as u64;
+
+    // For small number of free variables, enumerate
+    // For larger, use iterative deepening on sum
+
+    if n_free == 0 {
+        // Unique solution - check if it's valid
+        let mut solution = vec![0i64; n_buttons];
+        for (i, &pc) in pivot_cols.iter().enumerate() {
+            let pivot_val = aug[i][pc];
+            if pivot_val == 0 {
+                return u64::MAX;
+            }
+            if aug[i][n_cols] % pivot_val != 0 {
+                return u64::MAX; // Non-integer solution
+            }
+            solution[pc] = aug[i][n_cols] / pivot_val;
+            if solution[pc] < 0 {
+                return u64::MAX; // Negative solution
+            }
+        }
+        return solution.iter().map(|&x| x as u64).sum();
+    }
+
+    // Search for minimum sum solution
+    // Use iterative deepening on total sum
+    let mut best_sum = u64::MAX;
+
+    // Estimate upper bound on free variables
+    let free_upper_bounds: Vec<i64> = free_vars.iter().map(|_| (max_target + 1) as i64).collect();
+
+    // Generate combinations with increasing sum
+    let ctx = SearchContext {
+        aug: &aug,
+        pivot_cols: &pivot_cols,
+        free_vars: &free_vars,
+        upper_bounds: &free_upper_bounds,
+        n_buttons,
+    };
+    search_free_vars(&ctx, 0, &mut vec![0i64; n_free], 0, &mut best_sum);
+
+    best_sum
+}
+
+struct SearchContext<'a> {
+    aug: &'a [Vec<i64>],
+    pivot_cols: &'a [usize],
+    free_vars: &'a [usize],
+    upper_bounds: &'a [i64],
+    n_buttons: usize,
+}
+
+fn search_free_vars(
+    ctx: &SearchContext,
+    idx: usize,
+    free_vals: &mut Vec<i64>,
+    current_free_sum: u64,
+    best_sum: &mut u64,
+) {
+    if current_free_sum >= *best_sum {
+        return; // Prune - already worse than best
+    }
+
+    if idx == ctx.free_vars.len() {
+        // Evaluate this assignment
+        let mut solution = vec![0i64; ctx.n_buttons];
+
+        // Set free variables
+        for (i, &fv) in ctx.free_vars.iter().enumerate() {
+            solution[fv] = free_vals[i];
+        }
+
+        // Compute pivot variables
+        for (i, &pc) in ctx.pivot_cols.iter().enumerate() {
+            let pivot_val = ctx.aug[i][pc];
+            if pivot_val == 0 {
+                return;
+            }
+
+            let mut rhs = ctx.aug[i][ctx.n_buttons]; // The target column
+            for (j, &fv) in ctx.free_vars.iter().enumerate() {
+                rhs -= ctx.aug[i][fv] * free_vals[j];
+            }
+
+            if rhs % pivot_val != 0 {
+                return; // Non-integer
+            }
+            solution[pc] = rhs / pivot_val;
+            if solution[pc] < 0 {
+                return; // Negative
+            }
+        }
+
+        let total: u64 = solution.iter().map(|&x| x as u64).sum();
+        if total < *best_sum {
+            *best_sum = total;
+        }
+        return;
+    }
+
+    // Try values for free_vars[idx]
+    for val in 0..=ctx.upper_bounds[idx] {
+        free_vals[idx] = val;
+        let new_sum = current_free_sum + val as u64;
+        if new_sum >= *best_sum {
+            break; // Further values will only be worse
+        }
+        search_free_vars(ctx, idx + 1, free_vals, new_sum, best_sum);
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    /// One entry per machine line
+    type Parsed = Vec<Machine>;
+    type Answer1 = u64;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Span::new(input)
+            .lines()
+            .filter(|line| !line.is_blank())
+            .map(parse_machine)
+            .collect()
+    }
+
+    fn part1(machines: &Self::Parsed) -> u64 {
+        let mut total = 0u64;
+        for (target, buttons, _) in machines {
+            total +=
+                min_presses(target, buttons).expect("parse rejects unreachable light patterns");
+        }
+        total
+    }
+
+    fn part2(machines: &Self::Parsed) -> Answer {
+        let mut total = 0u64;
+        for (_, buttons, joltage) in machines {
+            let presses = min_presses_joltage(joltage, buttons);
+            if presses == u64::MAX {
+                // Requirements the buttons cannot meet together, which only
+                // solving reveals; parse rejects the simpler cases
+                return Answer::Unsolved;
+            }
+            total += presses;
+        }
+        total.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day10);
+
+    #[test]
+    fn test_parse_machine() {
+        let line = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let (target, buttons, joltage) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(target, vec![false, true, true, false]);
+        assert_eq!(
+            buttons,
+            vec![
+                vec![3],
+                vec![1, 3],
+                vec![2],
+                vec![2, 3],
+                vec![0, 2],
+                vec![0, 1]
+            ]
+        );
+        assert_eq!(joltage, vec![3, 5, 4, 7]);
+    }
+
+    #[test]
+    fn test_machine1() {
+        let line = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let (target, buttons, _) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses(&target, &buttons), Some(2));
+    }
+
+    #[test]
+    fn test_machine2() {
+        let line = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let (target, buttons, _) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses(&target, &buttons), Some(3));
+    }
+
+    #[test]
+    fn test_machine3() {
+        let line = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let (target, buttons, _) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses(&target, &buttons), Some(2));
+    }
+
+    #[test]
+    fn test_joltage1() {
+        let line = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let (_, buttons, joltage) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses_joltage(&joltage, &buttons), 10);
+    }
+
+    #[test]
+    fn test_joltage2() {
+        let line = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let (_, buttons, joltage) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses_joltage(&joltage, &buttons), 12);
+    }
+
+    #[test]
+    fn test_joltage3() {
+        let line = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let (_, buttons, joltage) = parse_machine(Span::new(line)).unwrap();
+        assert_eq!(min_presses_joltage(&joltage, &buttons), 11);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day10::parse("[.##.] (3) (1,3 {3,5,4,7}").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (1, 12, "unclosed '('")
+        );
+        let err = Day10::parse("[.#] (0) (2) {3,5}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 11));
+        let err = Day10::parse("[#] (0) {0}\n[.] {1}").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (
+                2,
+                6,
+                "joltage required on light 0, which no button is wired to"
+            )
+        );
+        let err = Day10::parse("[#.] (0,1) {1,1}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 2));
+        let lights = ".".repeat(65);
+        let err = Day10::parse(&format!("[{}] {{}}", lights)).err().unwrap();
+        assert_eq!(err.message, "expected at most 64 lights, found 65");
+    }
+}
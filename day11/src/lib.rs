@@ -0,0 +1,94 @@
+use common::graph::{Graph, NodeId};
+use common::parse::Span;
+use common::{ParseError, Solution};
+
+pub struct Day11;
+
+/// Device outputs, with the devices in an order every output points forward in
+#[derive(Debug)]
+pub struct Devices {
+    graph: Graph,
+    order: Vec<NodeId>,
+}
+
+impl Devices {
+    /// Number of paths between two named devices, 0 if either is missing
+    fn count_paths(&self, from: &str, to: &str) -> u128 {
+        match (self.graph.id(from), self.graph.id(to)) {
+            (Some(from), Some(to)) => self.graph.path_counts_in(&self.order, from)[to],
+            _ => 0,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Devices;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let graph = Graph::parse(input)?;
+        let Some(order) = graph.toposort() else {
+            // Path counts through a loop would be infinite
+            let cycle: Vec<&str> = graph
+                .find_cycle()
+                .expect("a graph without a topological order has a cycle")
+                .into_iter()
+                .map(|n| graph.name(n))
+                .collect();
+            let prefix = format!("{}: ", cycle[0]);
+            let input = Span::new(input);
+            let line = input
+                .lines()
+                .find(|line| line.as_str().trim_start().starts_with(&prefix))
+                .unwrap_or(input);
+            return Err(line.error(format!(
+                "device outputs loop: {} -> {}",
+                cycle.join(" -> "),
+                cycle[0]
+            )));
+        };
+        Ok(Devices { graph, order })
+    }
+
+    fn part1(devices: &Self::Parsed) -> u128 {
+        devices.count_paths("you", "out")
+    }
+
+    fn part2(devices: &Self::Parsed) -> u128 {
+        // In a DAG only one of dac and fft can come first, so at most one of
+        // these products is non-zero
+        let via = |first, second| {
+            devices.count_paths("svr", first)
+                * devices.count_paths(first, second)
+                * devices.count_paths(second, "out")
+        };
+        via("dac", "fft") + via("fft", "dac")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day11);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day11::parse("you: bbb ccc\nbbb ddd").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 1, "expected ': '")
+        );
+
+        let err = Day11::parse("you: aaa\naaa: bbb\nbbb: aaa out")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "device outputs loop: aaa -> bbb -> aaa")
+        );
+    }
+}
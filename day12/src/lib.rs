@@ -0,0 +1,301 @@
+use common::parse::Span;
+use common::{Answer, Grid, ParseError, Pos, Solution};
+use std::collections::HashSet;
+
+pub struct Day12;
+
+type Point = (i32, i32);
+type Shape = Vec<Point>;
+/// Region width, height and the number of presents of each shape
+type Region = (usize, usize, Vec<usize>);
+
+/// Parse the rows of a shape, where '#' is part of the present
+fn parse_shape(rows: &[Span]) -> Result<Shape, ParseError> {
+    let mut points = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (i, ch) in row.as_str().char_indices() {
+            match ch {
+                // Anything before is '.' or '#', so the byte index is the column
+                '#' => points.push((i as i32, y as i32)),
+                '.' => {}
+                _ => {
+                    return Err(row
+                        .slice(i..i + ch.len_utf8())
+                        .error(format!("expected '.' or '#', found '{}'", ch)));
+                }
+            }
+        }
+    }
+    Ok(normalize_shape(&points))
+}
+
+fn normalize_shape(points: &[Point]) -> Shape {
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let min_x = points.iter().map(|p| p.0).min().unwrap();
+    let min_y = points.iter().map(|p| p.1).min().unwrap();
+    let mut normalized: Vec<Point> = points.iter().map(|p| (p.0 - min_x, p.1 - min_y)).collect();
+    normalized.sort();
+    normalized
+}
+
+fn rotate_90(shape: &Shape) -> Shape {
+    let rotated: Vec<Point> = shape.iter().map(|&(x, y)| (y, -x)).collect();
+    normalize_shape(&rotated)
+}
+
+fn flip_horizontal(shape: &Shape) -> Shape {
+    let flipped: Vec<Point> = shape.iter().map(|&(x, y)| (-x, y)).collect();
+    normalize_shape(&flipped)
+}
+
+fn get_all_orientations(shape: &Shape) -> Vec<Shape> {
+    let mut orientations = HashSet::new();
+    let mut current = shape.clone();
+
+    for _ in 0..4 {
+        orientations.insert(current.clone());
+        current = rotate_90(&current);
+    }
+
+    current = flip_horizontal(shape);
+    for _ in 0..4 {
+        orientations.insert(current.clone());
+        current = rotate_90(&current);
+    }
+
+    orientations.into_iter().collect()
+}
+
+/// Shape blocks, each a `N:` header above its rows, then one region per line
+fn parse_input(input: &str) -> Result<(Vec<Vec<Shape>>, Vec<Region>), ParseError> {
+    let input = Span::new(input);
+    let mut sections: Vec<Span> = input.sections().collect();
+    let regions_section = sections
+        .pop()
+        .ok_or_else(|| input.error("expected shapes followed by regions"))?;
+    if sections.is_empty() {
+        return Err(regions_section.error("expected at least one shape before the regions"));
+    }
+
+    let mut shapes: Vec<Vec<Shape>> = Vec::new();
+    for shape_block in sections {
+        let lines: Vec<Span> = shape_block.lines().collect();
+        let (index, rest) = lines[0].split_once(":")?;
+        index.parse::<usize>()?;
+        if !rest.is_blank() {
+            return Err(rest.error("expected the shape's rows on the following lines"));
+        }
+        let shape = parse_shape(&lines[1..])?;
+        shapes.push(get_all_orientations(&shape));
+    }
+
+    let mut regions = Vec::new();
+    for line in regions_section.lines() {
+        let (dims, counts) = line.split_once(":")?;
+        let (width, height) = dims.split_once("x")?;
+        let counts: Vec<usize> = counts.ints(" ")?;
+        if counts.len() > shapes.len() {
+            return Err(line.error(format!(
+                "{} present counts given but only {} shapes",
+                counts.len(),
+                shapes.len()
+            )));
+        }
+        regions.push((width.parse()?, height.parse()?, counts));
+    }
+
+    Ok((shapes, regions))
+}
+
+// Grid cell under a square of a shape, or None if it falls outside the grid
+fn cell_at(grid: &Grid<bool>, x: i32, y: i32) -> Option<Pos> {
+    grid.offset((0, 0), (y as isize, x as isize))
+}
+
+// Check if shape can be placed with offset at (pos_x, pos_y)
+fn can_place_shape(grid: &Grid<bool>, shape: &Shape, pos_x: i32, pos_y: i32) -> bool {
+    shape
+        .iter()
+        .all(|&(dx, dy)| cell_at(grid, pos_x + dx, pos_y + dy).is_some_and(|pos| !grid[pos]))
+}
+
+// Mark the cells under a placed shape as filled or empty
+fn set_shape(grid: &mut Grid<bool>, shape: &Shape, pos_x: i32, pos_y: i32, filled: bool) {
+    for &(dx, dy) in shape {
+        let pos = cell_at(grid, pos_x + dx, pos_y + dy).expect("shape placed inside grid");
+        grid[pos] = filled;
+    }
+}
+
+// Get all ways to place a shape so that it covers a specific cell
+fn placements_covering_cell(
+    shape: &Shape,
+    (target_y, target_x): Pos,
+    grid: &Grid<bool>,
+) -> Vec<(i32, i32)> {
+    let mut placements = Vec::new();
+
+    for &(dx, dy) in shape {
+        let pos_x = target_x as i32 - dx;
+        let pos_y = target_y as i32 - dy;
+
+        if can_place_shape(grid, shape, pos_x, pos_y) {
+            placements.push((pos_x, pos_y));
+        }
+    }
+
+    placements
+}
+
+// Solve using first-empty-cell strategy (exact fit required)
+fn solve_exact(
+    grid: &mut Grid<bool>,
+    shapes_remaining: &mut Vec<usize>,
+    all_orientations: &[Vec<Shape>],
+) -> bool {
+    // Find first empty cell in row-major order
+    let target = match grid.find(&false) {
+        Some(pos) => pos,
+        None => return true, // Grid is full, success!
+    };
+
+    // Try each shape type that has remaining pieces
+    for shape_idx in 0..shapes_remaining.len() {
+        if shapes_remaining[shape_idx] == 0 {
+            continue;
+        }
+
+        // Try each orientation
+        for orientation in &all_orientations[shape_idx] {
+            let placements = placements_covering_cell(orientation, target, grid);
+
+            for (pos_x, pos_y) in placements {
+                set_shape(grid, orientation, pos_x, pos_y, true);
+                shapes_remaining[shape_idx] -= 1;
+
+                if solve_exact(grid, shapes_remaining, all_orientations) {
+                    return true;
+                }
+
+                shapes_remaining[shape_idx] += 1;
+                set_shape(grid, orientation, pos_x, pos_y, false);
+            }
+        }
+    }
+
+    false
+}
+
+fn can_fit_all_presents(
+    width: usize,
+    height: usize,
+    counts: &[usize],
+    all_shapes: &[Vec<Shape>],
+) -> bool {
+    // All shapes have same size
+    let shape_size = all_shapes[0][0].len();
+    let total_cells: usize = width * height;
+    let needed_cells: usize = counts.iter().sum::<usize>() * shape_size;
+
+    // Basic area check - must have enough space
+    if needed_cells > total_cells {
+        return false;
+    }
+
+    let mut shapes_remaining: Vec<usize> = counts.to_vec();
+    let mut grid = Grid::new(width, height, false);
+
+    // If exact fit is required (area matches exactly), use efficient solver
+    if needed_cells == total_cells {
+        return solve_exact(&mut grid, &mut shapes_remaining, all_shapes);
+    }
+
+    // For non-exact fits, we need a different approach
+    // This is expensive but necessary for correctness
+    solve_with_gaps(&mut grid, &mut shapes_remaining, all_shapes)
+}
+
+// Solve when gaps are allowed - simpler backtracking
+fn solve_with_gaps(
+    grid: &mut Grid<bool>,
+    shapes_remaining: &mut Vec<usize>,
+    all_orientations: &[Vec<Shape>],
+) -> bool {
+    // Check if all shapes are placed
+    let total_remaining: usize = shapes_remaining.iter().sum();
+    if total_remaining == 0 {
+        return true;
+    }
+
+    // Find first shape type with remaining pieces
+    let shape_idx = shapes_remaining.iter().position(|&c| c > 0).unwrap();
+
+    // Try each orientation
+    for orientation in &all_orientations[shape_idx] {
+        // Try each position
+        for (y, x) in grid.positions() {
+            let (pos_x, pos_y) = (x as i32, y as i32);
+            if can_place_shape(grid, orientation, pos_x, pos_y) {
+                set_shape(grid, orientation, pos_x, pos_y, true);
+                shapes_remaining[shape_idx] -= 1;
+
+                if solve_with_gaps(grid, shapes_remaining, all_orientations) {
+                    return true;
+                }
+
+                shapes_remaining[shape_idx] += 1;
+                set_shape(grid, orientation, pos_x, pos_y, false);
+            }
+        }
+    }
+
+    false
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    /// All orientations of each present shape, and the regions under the trees
+    type Parsed = (Vec<Vec<Shape>>, Vec<Region>);
+    type Answer1 = usize;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(puzzle: &Self::Parsed) -> usize {
+        let (shapes, regions) = puzzle;
+
+        let mut count = 0;
+        for (width, height, counts) in regions {
+            if can_fit_all_presents(*width, *height, counts, shapes) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn part2(_puzzle: &Self::Parsed) -> Answer {
+        // Day 12 has no second puzzle
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::aoc_tests!(Day12);
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day12::parse("0:\n##\n#x\n\n4x4: 1").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+        let err = Day12::parse("0:\n##\n\n4x4: 1 2").err().unwrap();
+        assert_eq!(err.message, "2 present counts given but only 1 shapes");
+    }
+}